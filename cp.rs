@@ -1,38 +1,656 @@
 // Singu Rcp IO template , finally !!!
+#[allow(dead_code)]
 mod rcp {
     use std::{
+        collections::VecDeque,
+        fmt::{Arguments, Display, Write as FmtWrite},
         fs::File,
-        io::{self, BufRead, BufReader},
+        io::{self, BufRead, BufReader, BufWriter, StdoutLock, Write},
     };
 
-    pub fn read<T: std::str::FromStr>(reader: &mut impl BufRead) -> T {
+    #[derive(Debug)]
+    pub enum ReadError {
+        Eof,
+        Io(io::Error),
+        // line is 1-based (lines read so far), col = byte offset of the token inside it
+        Parse {
+            token: String,
+            line: usize,
+            col: usize,
+        },
+    }
+
+    impl Display for ReadError {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            match self {
+                ReadError::Eof => write!(f, "unexpected end of input"),
+                ReadError::Io(e) => write!(f, "read_line failed: {}", e),
+                ReadError::Parse { token, line, col } => write!(
+                    f,
+                    "failed to parse {:?} at line {}, column {}",
+                    token, line, col
+                ),
+            }
+        }
+    }
+
+    // lines read so far, for error positions. readers are plain `impl BufRead` with no
+    // room for a counter, so it lives here; reset_line_count() starts a new input
+    thread_local! {
+        static LINES: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+    }
+
+    pub fn reset_line_count() {
+        LINES.with(|c| c.set(0));
+    }
+
+    fn current_line() -> usize {
+        LINES.with(|c| c.get())
+    }
+
+    fn count_line(n: usize) -> usize {
+        if n > 0 {
+            LINES.with(|c| c.set(c.get() + 1));
+        }
+        n
+    }
+
+    // every line based reader goes through these two so the count stays right
+    fn read_line_counted(reader: &mut impl BufRead, buf: &mut Vec<u8>) -> io::Result<usize> {
+        reader.read_until(b'\n', buf).map(count_line)
+    }
+
+    fn read_line_string(reader: &mut impl BufRead, line: &mut String) -> io::Result<usize> {
+        reader.read_line(line).map(count_line)
+    }
+
+    fn try_read_line(reader: &mut impl BufRead) -> Result<String, ReadError> {
         let mut line = String::new();
-        reader.read_line(&mut line).expect("read_line failed");
-        line.trim().parse().ok().expect("failed to parse")
+        match read_line_string(reader, &mut line) {
+            Ok(0) => Err(ReadError::Eof),
+            Ok(_) => Ok(line),
+            Err(e) => Err(ReadError::Io(e)),
+        }
+    }
+
+    fn try_parse<T: std::str::FromStr>(line: &str, tok: &str) -> Result<T, ReadError> {
+        tok.parse().map_err(|_| ReadError::Parse {
+            token: tok.to_string(),
+            line: current_line(),
+            col: tok.as_ptr() as usize - line.as_ptr() as usize,
+        })
+    }
+
+    pub fn try_read<T: std::str::FromStr>(reader: &mut impl BufRead) -> Result<T, ReadError> {
+        let line = try_read_line(reader)?;
+        try_parse(&line, line.trim())
+    }
+
+    pub fn try_read_vec<T: std::str::FromStr>(
+        reader: &mut impl BufRead,
+    ) -> Result<Vec<T>, ReadError> {
+        let line = try_read_line(reader)?;
+        line.split_whitespace()
+            .map(|tok| try_parse(&line, tok))
+            .collect()
+    }
+
+    pub fn read<T: std::str::FromStr>(reader: &mut impl BufRead) -> T {
+        try_read(reader).unwrap_or_else(|e| panic!("{}", e))
     }
 
     pub fn read_vec<T: std::str::FromStr>(reader: &mut impl BufRead) -> Vec<T> {
+        try_read_vec(reader).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    // no test count given, just stream until EOF
+    pub fn read_all_lines(reader: &mut impl BufRead) -> Vec<String> {
+        let mut res = Vec::new();
+        loop {
+            let mut line = String::new();
+            if read_line_string(reader, &mut line).expect("read_line failed") == 0 {
+                return res;
+            }
+            res.push(line.trim_end().to_string());
+        }
+    }
+
+    pub fn read_tokens_until_eof<T: std::str::FromStr>(reader: &mut impl BufRead) -> Vec<T> {
+        let mut res = Vec::new();
+        loop {
+            match try_read_vec(reader) {
+                Ok(v) => res.extend(v),
+                Err(ReadError::Eof) => return res,
+                Err(e) => panic!("{}", e),
+            }
+        }
+    }
+
+    // byte level i64 parsing, skips FromStr (big inputs). anything the fast path
+    // doesn't take (no digits, trailing junk, overflow, a leading '+') is parsed as a
+    // whole token, and panics with it if that fails too
+    fn parse_ints(line: &[u8]) -> Vec<i64> {
+        let mut res = Vec::new();
+        let mut i = 0;
+        while i < line.len() {
+            if line[i].is_ascii_whitespace() {
+                i += 1;
+                continue;
+            }
+            let start = i;
+            let neg = line[i] == b'-';
+            if neg {
+                i += 1;
+            }
+            let digits = i;
+            // accumulated negative so i64::MIN fits
+            let mut x = Some(0i64);
+            while i < line.len() && line[i].is_ascii_digit() {
+                let d = (line[i] - b'0') as i64;
+                x = x.and_then(|x| x.checked_mul(10)?.checked_sub(d));
+                i += 1;
+            }
+            let clean = i > digits && (i == line.len() || line[i].is_ascii_whitespace());
+            match x.and_then(|x| if neg { Some(x) } else { x.checked_neg() }) {
+                Some(x) if clean => res.push(x),
+                _ => {
+                    while i < line.len() && !line[i].is_ascii_whitespace() {
+                        i += 1;
+                    }
+                    let token = String::from_utf8_lossy(&line[start..i]).into_owned();
+                    let x = token.parse().unwrap_or_else(|_| {
+                        let line = current_line();
+                        panic!("{}", ReadError::Parse { token, line, col: start })
+                    });
+                    res.push(x);
+                }
+            }
+        }
+        res
+    }
+
+    pub fn read_int(reader: &mut impl BufRead) -> i64 {
+        *read_ints(reader)
+            .first()
+            .expect("read_int: no integer on the line (empty line or end of input)")
+    }
+
+    pub fn read_ints(reader: &mut impl BufRead) -> Vec<i64> {
+        let mut line = Vec::new();
+        read_line_counted(reader, &mut line).expect("read_line failed");
+        parse_ints(&line)
+    }
+
+    fn parse_token<T: std::str::FromStr>(tok: Option<&str>) -> T {
+        tok.expect("missing token")
+            .parse()
+            .ok()
+            .expect("failed to parse")
+    }
+
+    // n lines of "a b"
+    pub fn read_pairs<A: std::str::FromStr, B: std::str::FromStr>(
+        reader: &mut impl BufRead,
+        n: usize,
+    ) -> Vec<(A, B)> {
         let mut line = String::new();
-        reader.read_line(&mut line).expect("read_line failed");
-        line.split_whitespace()
-            .map(|s| s.parse().ok().expect("failed to parse"))
+        (0..n)
+            .map(|_| {
+                line.clear();
+                read_line_string(reader, &mut line).expect("read_line failed");
+                let mut it = line.split_whitespace();
+                (parse_token(it.next()), parse_token(it.next()))
+            })
             .collect()
     }
 
-    pub fn init_reader() -> Box<dyn BufRead> {
-        if std::env::args().any(|arg| arg == "--file") {
-            Box::new(BufReader::new(
-                File::open("input.txt").expect("failed to open 'input.txt'"),
-            ))
+    pub fn read_tuples3<A: std::str::FromStr, B: std::str::FromStr, C: std::str::FromStr>(
+        reader: &mut impl BufRead,
+        n: usize,
+    ) -> Vec<(A, B, C)> {
+        let mut line = String::new();
+        (0..n)
+            .map(|_| {
+                line.clear();
+                read_line_string(reader, &mut line).expect("read_line failed");
+                let mut it = line.split_whitespace();
+                (
+                    parse_token(it.next()),
+                    parse_token(it.next()),
+                    parse_token(it.next()),
+                )
+            })
+            .collect()
+    }
+
+    // same as read_pairs but 1-indexed input comes back 0-indexed (edge lists)
+    pub fn read_pairs_usize1(reader: &mut impl BufRead, n: usize) -> Vec<(usize, usize)> {
+        read_pairs::<usize, usize>(reader, n)
+            .into_iter()
+            .map(|(a, b)| (dec1(a), dec1(b)))
+            .collect()
+    }
+
+    // 1-indexed value -> 0-indexed, a 0 in the input means the problem wasn't 1-indexed
+    fn dec1(x: usize) -> usize {
+        x.checked_sub(1)
+            .expect("got 0 while reading a 1-indexed value")
+    }
+
+    pub fn read_usize1(reader: &mut impl BufRead) -> usize {
+        dec1(read(reader))
+    }
+
+    pub fn read_vec_usize1(reader: &mut impl BufRead) -> Vec<usize> {
+        read_vec(reader).into_iter().map(dec1).collect()
+    }
+
+    // trailing whitespace / '\r' is dropped from every row
+    pub fn read_grid(reader: &mut impl BufRead, rows: usize) -> Vec<Vec<u8>> {
+        (0..rows)
+            .map(|_| {
+                let mut line = String::new();
+                read_line_string(reader, &mut line).expect("read_line failed");
+                line.trim_end().as_bytes().to_vec()
+            })
+            .collect()
+    }
+
+    pub fn read_digit_grid(reader: &mut impl BufRead, rows: usize) -> Vec<Vec<u8>> {
+        read_grid(reader, rows)
+            .into_iter()
+            .enumerate()
+            .map(|(i, row)| {
+                row.into_iter()
+                    .enumerate()
+                    .map(|(j, c)| {
+                        assert!(
+                            c.is_ascii_digit(),
+                            "digit grid: {:?} at row {}, column {}",
+                            c as char,
+                            i,
+                            j
+                        );
+                        c - b'0'
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    // RCP_INPUT=path wins, then `--file` (reads input.txt), else stdin
+    fn input_path() -> Option<String> {
+        if let Ok(path) = std::env::var("RCP_INPUT") {
+            Some(path)
+        } else if std::env::args().any(|arg| arg == "--file") {
+            Some("input.txt".to_string())
         } else {
-            Box::new(BufReader::new(io::stdin()))
+            None
+        }
+    }
+
+    pub fn init_reader() -> Box<dyn BufRead> {
+        match input_path() {
+            Some(path) => Box::new(BufReader::new(
+                File::open(&path).unwrap_or_else(|_| panic!("failed to open '{}'", path)),
+            )),
+            None => Box::new(BufReader::new(io::stdin())),
+        }
+    }
+
+    // RCP_OUTPUT=path writes there instead of stdout
+    pub fn init_writer() -> OutWriter<Box<dyn Write>> {
+        match std::env::var("RCP_OUTPUT") {
+            Ok(path) => OutWriter::new(Box::new(
+                File::create(&path).unwrap_or_else(|_| panic!("failed to create '{}'", path)),
+            )),
+            Err(_) => OutWriter::new(Box::new(io::stdout().lock())),
+        }
+    }
+
+    // one String for the whole vector, then a single write
+    pub fn vec_to_string<T: Display>(v: &[T], sep: &str) -> String {
+        let mut res = String::new();
+        for (i, x) in v.iter().enumerate() {
+            if i > 0 {
+                res.push_str(sep);
+            }
+            write!(res, "{}", x).expect("write failed");
+        }
+        res
+    }
+
+    // goes through the given writer (an OutWriter) instead of println!, so it stays buffered
+    pub fn print_vec<T: Display>(out: &mut impl Write, v: &[T], sep: &str) {
+        writeln!(out, "{}", vec_to_string(v, sep)).expect("write failed");
+    }
+
+    // println! locks + flushes every line, this buffers everything until drop
+    pub struct OutWriter<W: Write> {
+        out: BufWriter<W>,
+    }
+
+    impl OutWriter<StdoutLock<'static>> {
+        pub fn stdout() -> Self {
+            Self::new(io::stdout().lock())
+        }
+    }
+
+    impl<W: Write> OutWriter<W> {
+        pub fn new(w: W) -> Self {
+            Self {
+                out: BufWriter::new(w),
+            }
+        }
+
+        pub fn into_inner(self) -> W {
+            self.out
+                .into_inner()
+                .unwrap_or_else(|_| panic!("flush failed"))
+        }
+
+        pub fn write_line<T: Display>(&mut self, x: T) {
+            writeln!(self.out, "{}", x).expect("write failed");
+        }
+
+        pub fn write_vec<T: Display>(&mut self, v: &[T], sep: &str) {
+            self.write_line(vec_to_string(v, sep));
+        }
+
+        // itoa style, no fmt machinery
+        pub fn write_int(&mut self, x: i64) {
+            let mut buf = [0u8; 20];
+            let mut i = buf.len();
+            let mut v = x.unsigned_abs();
+            loop {
+                i -= 1;
+                buf[i] = b'0' + (v % 10) as u8;
+                v /= 10;
+                if v == 0 {
+                    break;
+                }
+            }
+            if x < 0 {
+                i -= 1;
+                buf[i] = b'-';
+            }
+            self.out.write_all(&buf[i..]).expect("write failed");
+        }
+
+        // i128/u128 answers, 39 digits max
+        pub fn write_u128(&mut self, mut v: u128) {
+            let mut buf = [0u8; 39];
+            let mut i = buf.len();
+            loop {
+                i -= 1;
+                buf[i] = b'0' + (v % 10) as u8;
+                v /= 10;
+                if v == 0 {
+                    break;
+                }
+            }
+            self.out.write_all(&buf[i..]).expect("write failed");
+        }
+
+        pub fn write_i128(&mut self, x: i128) {
+            if x < 0 {
+                self.out.write_all(b"-").expect("write failed");
+            }
+            self.write_u128(x.unsigned_abs());
+        }
+
+        pub fn write_ints(&mut self, v: &[i64], sep: &str) {
+            for (i, &x) in v.iter().enumerate() {
+                if i > 0 {
+                    self.out.write_all(sep.as_bytes()).expect("write failed");
+                }
+                self.write_int(x);
+            }
+            self.out.write_all(b"\n").expect("write failed");
+        }
+
+        pub fn write_yes_no(&mut self, b: bool) {
+            self.write_line(if b { "YES" } else { "NO" });
+        }
+    }
+
+    impl<W: Write> Write for OutWriter<W> {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.out.write(buf)
+        }
+        fn flush(&mut self) -> io::Result<()> {
+            self.out.flush()
+        }
+    }
+
+    // reads t, builds the shared tables once (sieve, factorials, ..) and runs every case
+    // against them, so nothing big gets reallocated per test
+    pub fn run_tests<R: BufRead, W: Write, P>(
+        reader: &mut R,
+        out: &mut OutWriter<W>,
+        precompute: impl FnOnce() -> P,
+        mut solve_case: impl FnMut(&mut R, &mut OutWriter<W>, &P),
+    ) {
+        let t: usize = read(reader);
+        let pre = precompute();
+        for _ in 0..t {
+            solve_case(reader, out, &pre);
+        }
+    }
+
+    // interactive problems: every line sent is flushed right away.
+    // write solve against `impl Interact` so it runs on the judge and on a mock
+    pub trait Interact {
+        fn send(&mut self, line: Arguments);
+        fn recv_line(&mut self) -> String;
+
+        fn query<T: std::str::FromStr>(&mut self, q: Arguments) -> T {
+            self.send(q);
+            self.recv_line()
+                .trim()
+                .parse()
+                .ok()
+                .expect("failed to parse")
+        }
+
+        fn answer(&mut self, a: Arguments) {
+            self.send(a);
+        }
+    }
+
+    pub struct Interactor<R: BufRead, W: Write> {
+        reader: R,
+        writer: W,
+    }
+
+    impl<R: BufRead, W: Write> Interactor<R, W> {
+        pub fn new(reader: R, writer: W) -> Self {
+            Self { reader, writer }
+        }
+    }
+
+    impl<R: BufRead, W: Write> Interact for Interactor<R, W> {
+        fn send(&mut self, line: Arguments) {
+            writeln!(self.writer, "{}", line).expect("write failed");
+            self.writer.flush().expect("flush failed");
+        }
+
+        fn recv_line(&mut self) -> String {
+            let mut line = String::new();
+            self.reader.read_line(&mut line).expect("read_line failed");
+            line
+        }
+    }
+
+    // judge closure gets every line the solution sends and may reply with one line
+    pub struct MockInteractor<F: FnMut(&str) -> Option<String>> {
+        judge: F,
+        pending: VecDeque<String>,
+        pub sent: Vec<String>,
+    }
+
+    impl<F: FnMut(&str) -> Option<String>> MockInteractor<F> {
+        pub fn new(judge: F) -> Self {
+            Self {
+                judge,
+                pending: VecDeque::new(),
+                sent: Vec::new(),
+            }
+        }
+    }
+
+    impl<F: FnMut(&str) -> Option<String>> Interact for MockInteractor<F> {
+        fn send(&mut self, line: Arguments) {
+            let line = line.to_string();
+            if let Some(reply) = (self.judge)(&line) {
+                self.pending.push_back(reply);
+            }
+            self.sent.push(line);
+        }
+
+        fn recv_line(&mut self) -> String {
+            self.pending
+                .pop_front()
+                .expect("judge did not reply to the last query")
         }
     }
 }
+// `./sol --samples` runs solve on every tests/samples/NAME.in and diffs against NAME.out
+#[allow(dead_code)]
+mod testing {
+    use std::fs;
+
+    pub enum Compare {
+        Exact,
+        // tokens that parse as floats only need abs or rel error <= eps
+        Float(f64),
+        // any order of output lines is accepted
+        Unordered,
+    }
+
+    fn lines(s: &str) -> Vec<String> {
+        s.lines()
+            .map(|l| l.split_whitespace().collect::<Vec<_>>().join(" "))
+            .filter(|l| !l.is_empty())
+            .collect()
+    }
+
+    // a is the expected token: like judges, the relative error is measured against it
+    fn token_eq(a: &str, b: &str, eps: f64) -> bool {
+        if a == b {
+            return true;
+        }
+        match (a.parse::<f64>(), b.parse::<f64>()) {
+            (Ok(x), Ok(y)) => (x - y).abs() <= eps * x.abs().max(1.0),
+            _ => false,
+        }
+    }
+
+    pub fn check(expected: &str, got: &str, cmp: &Compare) -> bool {
+        let (mut e, mut g) = (lines(expected), lines(got));
+        match cmp {
+            Compare::Exact => e == g,
+            Compare::Unordered => {
+                e.sort();
+                g.sort();
+                e == g
+            }
+            Compare::Float(eps) => {
+                let et: Vec<&str> = e.iter().flat_map(|l| l.split(' ')).collect();
+                let gt: Vec<&str> = g.iter().flat_map(|l| l.split(' ')).collect();
+                et.len() == gt.len() && et.iter().zip(&gt).all(|(a, b)| token_eq(a, b, *eps))
+            }
+        }
+    }
+
+    pub fn run_samples(dir: &str, run: fn(&str) -> String, cmp: Compare) -> bool {
+        let mut ins: Vec<_> = fs::read_dir(dir)
+            .unwrap_or_else(|_| panic!("no sample dir '{}'", dir))
+            .map(|e| e.expect("read_dir failed").path())
+            .filter(|p| p.extension().is_some_and(|x| x == "in"))
+            .collect();
+        ins.sort();
+        let mut passed = 0;
+        for input in &ins {
+            let name = input.file_stem().unwrap().to_string_lossy();
+            let expected = match fs::read_to_string(input.with_extension("out")) {
+                Ok(s) => s,
+                Err(_) => {
+                    eprintln!("{}: no .out file, skipped", name);
+                    continue;
+                }
+            };
+            let got = run(&fs::read_to_string(input).expect("failed to read sample"));
+            if check(&expected, &got, &cmp) {
+                passed += 1;
+                eprintln!("{}: OK", name);
+            } else {
+                eprintln!("{}: WA\n--- expected\n{}--- got\n{}", name, expected, got);
+            }
+        }
+        eprintln!("{}/{} passed", passed, ins.len());
+        passed == ins.len()
+    }
+}
+// generates main(): `cp_main!(solve)` single test, `cp_main!(multi, solve)` reads t first
+// and calls solve t times, `cp_main!(interactive, solve)` hands solve an Interactor
+// (flushes every line). the first two also get the --samples flag
+#[allow(unused_macros)]
+macro_rules! cp_main {
+    ($solve:ident) => {
+        fn run_all(reader: &mut impl std::io::BufRead, out: &mut rcp::OutWriter<impl std::io::Write>) {
+            $solve(reader, out);
+        }
+        cp_main!(@main);
+    };
+    (multi, $solve:ident) => {
+        fn run_all(reader: &mut impl std::io::BufRead, out: &mut rcp::OutWriter<impl std::io::Write>) {
+            let t: usize = rcp::read(reader);
+            for _ in 0..t {
+                $solve(reader, out);
+            }
+        }
+        cp_main!(@main);
+    };
+    (interactive, $solve:ident) => {
+        fn main() {
+            let mut io = rcp::Interactor::new(std::io::stdin().lock(), std::io::stdout().lock());
+            $solve(&mut io);
+        }
+    };
+    (@main) => {
+        fn main() {
+            if std::env::args().any(|a| a == "--samples") {
+                let run = |input: &str| {
+                    rcp::reset_line_count();
+                    let mut out = rcp::OutWriter::new(Vec::new());
+                    run_all(&mut input.as_bytes(), &mut out);
+                    String::from_utf8(out.into_inner()).expect("output is not utf8")
+                };
+                // non-zero exit on any WA so scripts / CI can tell
+                if !testing::run_samples("tests/samples", run, testing::Compare::Exact) {
+                    std::process::exit(1);
+                }
+                return;
+            }
+            let mut reader = rcp::init_reader();
+            let mut out = rcp::init_writer();
+            run_all(&mut reader, &mut out);
+        }
+    };
+}
+use std::io::{BufRead, Write};
 // okay write from here , should be fairly simple (or not)
-fn solve(){
+#[allow(unused_variables)]
+fn solve(reader: &mut impl BufRead, out: &mut rcp::OutWriter<impl Write>){
     
 }
-fn main() {
-    
+// feeds `input` to solve and hands back everything it printed, for asserts on samples
+#[allow(dead_code)]
+fn run_solve(input: &str) -> String {
+    rcp::reset_line_count();
+    let mut out = rcp::OutWriter::new(Vec::new());
+    solve(&mut input.as_bytes(), &mut out);
+    String::from_utf8(out.into_inner()).expect("output is not utf8")
 }
+cp_main!(solve);
@@ -1,37 +1,283 @@
-use std::collections::BTreeMap;
-use std::io::{self, Read, Write};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::{Display, Write as FmtWrite};
+use std::io::{self, BufWriter, Read, StdoutLock, Write};
 const MOD: u64 = 1000000007;
 
+// dp relaxation: `if chmin!(dist[v], dist[u] + w) { .. }`, true when x changed
+#[allow(unused_macros)]
+macro_rules! chmin {
+    ($x:expr, $y:expr) => {{
+        let y = $y;
+        if y < $x {
+            $x = y;
+            true
+        } else {
+            false
+        }
+    }};
+}
+#[allow(unused_macros)]
+macro_rules! chmax {
+    ($x:expr, $y:expr) => {{
+        let y = $y;
+        if y > $x {
+            $x = y;
+            true
+        } else {
+            false
+        }
+    }};
+}
+
+// sort_together!(keys, a, b) sorts keys (stable) and moves a[i], b[i] along with keys[i]
+#[allow(unused_macros)]
+macro_rules! sort_together {
+    ($keys:expr $(, $v:expr)* $(,)?) => {{
+        let idx = $crate::argsort(&$keys);
+        $crate::apply_permutation(&mut $keys, &idx);
+        $($crate::apply_permutation(&mut $v, &idx);)*
+    }};
+}
+
+// debug output is on in dev builds (`cargo run`, plain `rustc sol.rs`) and off in
+// optimized ones (`cargo run --release`, `rustc -O`, what judges use), where every
+// dbg_print!/trace!/debug:: print compiles to nothing. `--features local` (or
+// `rustc -O --cfg 'feature="local"'`) turns it on in a release build too.
+// dbg_print!(i, dp) -> `[line] i = 3, dp = [..]` in cyan on stderr
+#[allow(unused_macros)]
+macro_rules! dbg_print {
+    ($($x:expr),* $(,)?) => {
+        if $crate::debug::ON {
+            let args: Vec<String> = vec![$(format!("{} = {:?}", stringify!($x), $x)),*];
+            eprintln!("\x1b[36m[{}] {}\x1b[0m", line!(), args.join(", "));
+        }
+    };
+}
+// assert_dbg!(i < n, i, n, dp) prints every listed expression before panicking.
+// in release builds the condition is not even evaluated
+#[allow(unused_macros)]
+macro_rules! assert_dbg {
+    ($cond:expr $(, $x:expr)* $(,)?) => {
+        if cfg!(debug_assertions) && !$cond {
+            if $crate::debug::ON {
+                $(eprintln!("{} = {:?}", stringify!($x), $x);)*
+            }
+            panic!("assertion failed: {}", stringify!($cond));
+        }
+    };
+}
+// same dump, then unreachable!()
+#[allow(unused_macros)]
+macro_rules! unreachable_dbg {
+    ($($x:expr),* $(,)?) => {{
+        if $crate::debug::ON {
+            $(eprintln!("{} = {:?}", stringify!($x), $x);)*
+        }
+        unreachable!()
+    }};
+}
+
+// trace!("dfs", v, depth) at the top of a function logs entry and exit, indented
+// by recursion depth. flip TRACE (or shadow it with a const inside a mod) to mute
+#[allow(dead_code)]
+const TRACE: bool = true;
+#[allow(unused_macros)]
+macro_rules! trace {
+    ($name:expr $(, $x:expr)* $(,)?) => {
+        let _trace_guard = $crate::debug::TraceGuard::enter(TRACE, || {
+            let args: Vec<String> = vec![$(format!("{} = {:?}", stringify!($x), $x)),*];
+            format!("{}({})", $name, args.join(", "))
+        });
+    };
+}
+
+#[derive(Debug)]
+enum ReadError {
+    Eof,
+    // byte offset into the whole input, line is 1-based
+    Parse {
+        token: String,
+        byte: usize,
+        line: usize,
+    },
+}
+impl Display for ReadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ReadError::Eof => write!(f, "unexpected end of input"),
+            ReadError::Parse { token, byte, line } => {
+                write!(
+                    f,
+                    "Parse error: {:?} at line {} (byte {})",
+                    token, line, byte
+                )
+            }
+        }
+    }
+}
+
 struct Scanner {
     input: Vec<u8>,
     index: usize,
 }
+#[allow(dead_code)]
 impl Scanner {
+    // RCP_INPUT=path or `--file` (input.txt) read from a file instead of stdin
     fn new() -> Self {
-        let mut input = String::new();
-        io::stdin().read_to_string(&mut input).unwrap();
+        let path = std::env::var("RCP_INPUT").ok().or_else(|| {
+            std::env::args()
+                .any(|a| a == "--file")
+                .then(|| "input.txt".to_string())
+        });
+        let input = match path {
+            Some(path) => std::fs::read_to_string(&path)
+                .unwrap_or_else(|_| panic!("failed to open '{}'", path)),
+            None => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input).unwrap();
+                input
+            }
+        };
         Self {
             input: input.into_bytes(),
             index: 0,
         }
     }
-    fn next<T: std::str::FromStr>(&mut self) -> T {
+    #[allow(dead_code)]
+    fn from_input(input: &str) -> Self {
+        Self {
+            input: input.as_bytes().to_vec(),
+            index: 0,
+        }
+    }
+    fn try_next<T: std::str::FromStr>(&mut self) -> Result<T, ReadError> {
         while self.index < self.input.len() && self.input[self.index].is_ascii_whitespace() {
             self.index += 1;
         }
+        if self.index == self.input.len() {
+            return Err(ReadError::Eof);
+        }
         let start = self.index;
         while self.index < self.input.len() && !self.input[self.index].is_ascii_whitespace() {
             self.index += 1;
         }
-        std::str::from_utf8(&self.input[start..self.index])
-            .unwrap()
+        let bytes = &self.input[start..self.index];
+        let err = || ReadError::Parse {
+            token: String::from_utf8_lossy(bytes).into_owned(),
+            byte: start,
+            line: self.input[..start].iter().filter(|&&c| c == b'\n').count() + 1,
+        };
+        std::str::from_utf8(bytes)
+            .map_err(|_| err())?
             .parse()
-            .ok()
-            .expect("Parse error")
+            .map_err(|_| err())
+    }
+    fn try_next_vec<T: std::str::FromStr>(&mut self, n: usize) -> Result<Vec<T>, ReadError> {
+        (0..n).map(|_| self.try_next()).collect()
+    }
+    // parses digits straight from the bytes, no utf8 validation or FromStr. anything
+    // the fast path doesn't take (no digits, trailing junk, overflow, a leading '+') goes
+    // through next() instead, which parses it or panics with the token and line
+    fn next_int(&mut self) -> i64 {
+        if self.is_eof() {
+            panic!("{}", ReadError::Eof);
+        }
+        let start = self.index;
+        let neg = self.input[self.index] == b'-';
+        if neg {
+            self.index += 1;
+        }
+        let digits = self.index;
+        // accumulated negative so i64::MIN fits
+        let mut x = Some(0i64);
+        while self.index < self.input.len() && self.input[self.index].is_ascii_digit() {
+            let d = (self.input[self.index] - b'0') as i64;
+            x = x.and_then(|x| x.checked_mul(10)?.checked_sub(d));
+            self.index += 1;
+        }
+        let clean = self.index > digits
+            && (self.index == self.input.len() || self.input[self.index].is_ascii_whitespace());
+        match x.and_then(|x| if neg { Some(x) } else { x.checked_neg() }) {
+            Some(x) if clean => x,
+            _ => {
+                self.index = start;
+                self.next()
+            }
+        }
+    }
+    fn next_ints(&mut self, n: usize) -> Vec<i64> {
+        (0..n).map(|_| self.next_int()).collect()
+    }
+    fn is_eof(&mut self) -> bool {
+        while self.index < self.input.len() && self.input[self.index].is_ascii_whitespace() {
+            self.index += 1;
+        }
+        self.index == self.input.len()
+    }
+    fn next_tokens_until_eof<T: std::str::FromStr>(&mut self) -> Vec<T> {
+        let mut res = Vec::new();
+        while !self.is_eof() {
+            res.push(self.next());
+        }
+        res
+    }
+    fn next<T: std::str::FromStr>(&mut self) -> T {
+        self.try_next().unwrap_or_else(|e| panic!("{}", e))
     }
     fn next_vec<T: std::str::FromStr>(&mut self, n: usize) -> Vec<T> {
         (0..n).map(|_| self.next()).collect()
     }
+    fn next_pairs<A: std::str::FromStr, B: std::str::FromStr>(&mut self, n: usize) -> Vec<(A, B)> {
+        (0..n).map(|_| (self.next(), self.next())).collect()
+    }
+    fn next_tuples3<A: std::str::FromStr, B: std::str::FromStr, C: std::str::FromStr>(
+        &mut self,
+        n: usize,
+    ) -> Vec<(A, B, C)> {
+        (0..n)
+            .map(|_| (self.next(), self.next(), self.next()))
+            .collect()
+    }
+    // 1-indexed pairs read as 0-indexed
+    fn next_pairs_usize1(&mut self, n: usize) -> Vec<(usize, usize)> {
+        (0..n)
+            .map(|_| (self.next_usize1(), self.next_usize1()))
+            .collect()
+    }
+    // a 0 here means the input wasn't 1-indexed after all
+    fn next_usize1(&mut self) -> usize {
+        self.next::<usize>()
+            .checked_sub(1)
+            .expect("got 0 while reading a 1-indexed value")
+    }
+    fn next_vec_usize1(&mut self, n: usize) -> Vec<usize> {
+        (0..n).map(|_| self.next_usize1()).collect()
+    }
+    fn next_grid(&mut self, rows: usize) -> Vec<Vec<u8>> {
+        (0..rows)
+            .map(|_| self.next::<String>().into_bytes())
+            .collect()
+    }
+    fn next_digit_grid(&mut self, rows: usize) -> Vec<Vec<u8>> {
+        (0..rows)
+            .map(|i| {
+                let row = self.next::<String>().into_bytes();
+                row.iter()
+                    .enumerate()
+                    .map(|(j, &c)| {
+                        assert!(
+                            c.is_ascii_digit(),
+                            "digit grid: {:?} at row {}, column {}",
+                            c as char,
+                            i,
+                            j
+                        );
+                        c - b'0'
+                    })
+                    .collect()
+            })
+            .collect()
+    }
     fn next_line(&mut self) -> String {
         while self.index < self.input.len() && self.input[self.index].is_ascii_whitespace() {
             self.index += 1;
@@ -49,6 +295,133 @@ impl Scanner {
         line
     }
 }
+// one String for the whole vector, then a single write
+fn vec_to_string<T: Display>(v: &[T], sep: &str) -> String {
+    let mut res = String::new();
+    for (i, x) in v.iter().enumerate() {
+        if i > 0 {
+            res.push_str(sep);
+        }
+        write!(res, "{}", x).expect("write failed");
+    }
+    res
+}
+// goes through the given writer (an OutWriter) instead of println!, so it stays buffered
+#[allow(dead_code)]
+fn print_vec<T: Display>(out: &mut impl Write, v: &[T], sep: &str) {
+    writeln!(out, "{}", vec_to_string(v, sep)).expect("write failed");
+}
+struct OutWriter<W: Write> {
+    out: BufWriter<W>,
+}
+#[allow(dead_code)]
+impl OutWriter<StdoutLock<'static>> {
+    fn stdout() -> Self {
+        Self::new(io::stdout().lock())
+    }
+}
+// RCP_OUTPUT=path writes there instead of stdout
+fn init_writer() -> OutWriter<Box<dyn Write>> {
+    match std::env::var("RCP_OUTPUT") {
+        Ok(path) => OutWriter::new(Box::new(
+            std::fs::File::create(&path).unwrap_or_else(|_| panic!("failed to create '{}'", path)),
+        )),
+        Err(_) => OutWriter::new(Box::new(io::stdout().lock())),
+    }
+}
+// reads t, builds the shared tables once (sieve, factorials, ..) and runs every case
+// against them, so nothing big gets reallocated per test
+#[allow(dead_code)]
+fn run_tests<W: Write, P>(
+    scan: &mut Scanner,
+    out: &mut OutWriter<W>,
+    precompute: impl FnOnce() -> P,
+    mut solve_case: impl FnMut(&mut Scanner, &mut OutWriter<W>, &P),
+) {
+    let t: usize = scan.next();
+    let pre = precompute();
+    for _ in 0..t {
+        solve_case(scan, out, &pre);
+    }
+}
+#[allow(dead_code)]
+impl<W: Write> OutWriter<W> {
+    fn new(w: W) -> Self {
+        Self {
+            out: BufWriter::new(w),
+        }
+    }
+    fn into_inner(self) -> W {
+        self.out
+            .into_inner()
+            .unwrap_or_else(|_| panic!("flush failed"))
+    }
+    fn write_line<T: Display>(&mut self, x: T) {
+        writeln!(self.out, "{}", x).expect("write failed");
+    }
+    fn write_vec<T: Display>(&mut self, v: &[T], sep: &str) {
+        self.write_line(vec_to_string(v, sep));
+    }
+    // itoa style, no fmt machinery
+    fn write_int(&mut self, x: i64) {
+        let mut buf = [0u8; 20];
+        let mut i = buf.len();
+        let mut v = x.unsigned_abs();
+        loop {
+            i -= 1;
+            buf[i] = b'0' + (v % 10) as u8;
+            v /= 10;
+            if v == 0 {
+                break;
+            }
+        }
+        if x < 0 {
+            i -= 1;
+            buf[i] = b'-';
+        }
+        self.out.write_all(&buf[i..]).expect("write failed");
+    }
+    // i128/u128 answers, 39 digits max
+    fn write_u128(&mut self, mut v: u128) {
+        let mut buf = [0u8; 39];
+        let mut i = buf.len();
+        loop {
+            i -= 1;
+            buf[i] = b'0' + (v % 10) as u8;
+            v /= 10;
+            if v == 0 {
+                break;
+            }
+        }
+        self.out.write_all(&buf[i..]).expect("write failed");
+    }
+    fn write_i128(&mut self, x: i128) {
+        if x < 0 {
+            self.out.write_all(b"-").expect("write failed");
+        }
+        self.write_u128(x.unsigned_abs());
+    }
+    fn write_ints(&mut self, v: &[i64], sep: &str) {
+        for (i, &x) in v.iter().enumerate() {
+            if i > 0 {
+                self.out.write_all(sep.as_bytes()).expect("write failed");
+            }
+            self.write_int(x);
+        }
+        self.out.write_all(b"\n").expect("write failed");
+    }
+    fn write_yes_no(&mut self, b: bool) {
+        self.write_line(if b { "YES" } else { "NO" });
+    }
+}
+impl<W: Write> Write for OutWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.out.write(buf)
+    }
+    fn flush(&mut self) -> io::Result<()> {
+        self.out.flush()
+    }
+}
 #[allow(dead_code)]
 fn modexp(mut base: u64, mut exp: u64, m: u64) -> u64 {
     let mut ans = 1;
@@ -69,24 +442,68 @@ fn modexp(mut base: u64, mut exp: u64, m: u64) -> u64 {
         }
     }
 }
+// the integer types the generic helpers below accept (gcd, PrefixSum, Fenwick, ..)
+// so u64 / i128 data doesn't need casting to i64 first
+trait Num:
+    Copy
+    + PartialOrd
+    + std::fmt::Debug
+    + std::ops::Add<Output = Self>
+    + std::ops::Sub<Output = Self>
+    + std::ops::Mul<Output = Self>
+    + std::ops::Div<Output = Self>
+    + std::ops::Rem<Output = Self>
+    + std::ops::AddAssign
+    + std::ops::SubAssign
+{
+    fn zero() -> Self;
+    fn one() -> Self;
+    fn checked_add(self, o: Self) -> Option<Self>;
+    fn checked_sub(self, o: Self) -> Option<Self>;
+    fn checked_mul(self, o: Self) -> Option<Self>;
+}
+macro_rules! impl_num {
+    ($($t:ty),*) => {$(
+        impl Num for $t {
+            fn zero() -> Self { 0 }
+            fn one() -> Self { 1 }
+            fn checked_add(self, o: Self) -> Option<Self> { <$t>::checked_add(self, o) }
+            fn checked_sub(self, o: Self) -> Option<Self> { <$t>::checked_sub(self, o) }
+            fn checked_mul(self, o: Self) -> Option<Self> { <$t>::checked_mul(self, o) }
+        }
+    )*};
+}
+impl_num!(i32, i64, i128, isize, u32, u64, u128, usize);
+
+// always >= 0, also for negative signed inputs (gcd(-4, 6) = 2)
 #[allow(dead_code)]
-fn gcd(mut a: usize, mut b: usize) -> usize {
-    if a == b {
-        return a;
-    }
-    if b > a {
-        std::mem::swap(&mut a, &mut b);
-    }
-    while b > 0 {
-        let temp = a;
+fn gcd<T: Num>(mut a: T, mut b: T) -> T {
+    while b != T::zero() {
+        let temp = a % b;
         a = b;
-        b = temp % b;
+        b = temp;
+    }
+    if a < T::zero() {
+        T::zero() - a
+    } else {
+        a
     }
-    return a;
 }
 
-fn lcm(a: usize, b: usize) -> usize {
-    return a * (b / gcd(a, b));
+// panics instead of wrapping when the lcm doesn't fit in T
+fn lcm<T: Num>(a: T, b: T) -> T {
+    let g = gcd(a, b);
+    if g == T::zero() {
+        return T::zero();
+    }
+    let l = a
+        .checked_mul(b / g)
+        .unwrap_or_else(|| panic!("lcm({:?}, {:?}) overflows", a, b));
+    if l < T::zero() {
+        T::zero() - l
+    } else {
+        l
+    }
 }
 
 #[allow(dead_code)]
@@ -226,32 +643,4462 @@ fn pfactors(mut n: u64) -> BTreeMap<u64, u32> {
     fcts1
 }
 
-fn solve(scan: &mut Scanner, out: &mut dyn Write) {
-    let mut n: usize = scan.next();
-    let a: usize = scan.next();
-    let b: usize = scan.next();
-    let n1: usize = scan.next();
-    let r: usize = scan.next();
-    let a1 = factors(n as i128);
-    writeln!(out, "{:?}", a1).ok();
-    let gc = gcd(a, b);
-    writeln!(out, "{} {}", gc, lcm(a, b)).ok();
-    let val = (5 * fltinv(3, MOD)) % MOD;
-    writeln!(out, "{}", val).ok();
-    writeln!(out, "{}", modexp(2, 100000, 1000000007)).ok();
-    writeln!(out, "{:?}", pcfact(n)).ok();
-    writeln!(out, "{}", intsqrt(n)).ok();
-    let (fact, inv_fact) = pcfact(n);
-    let combinations = ncr(n1, r, &fact, &inv_fact);
-    let permutations = npr(n1, r, &fact, &inv_fact);
-    writeln!(out, "15C3 = {}", combinations).ok();
-    writeln!(out, "15P3 = {}", permutations).ok();
-    writeln!(out, "{:?}", sieve(n)).ok();
-    writeln!(out, "{:?}", pfactors(n)).ok();
+// pre[i] = a[0] + .. + a[i - 1], sum(l..r) is 0 for empty ranges
+#[allow(dead_code)]
+struct PrefixSum<T = i64> {
+    pre: Vec<T>,
+}
+#[allow(dead_code)]
+impl<T: Num> PrefixSum<T> {
+    fn new(a: &[T]) -> Self {
+        let mut pre = vec![T::zero(); a.len() + 1];
+        for i in 0..a.len() {
+            pre[i + 1] = pre[i] + a[i];
+        }
+        Self { pre }
+    }
+    fn sum(&self, r: std::ops::Range<usize>) -> T {
+        if r.start >= r.end {
+            return T::zero();
+        }
+        self.pre[r.end] - self.pre[r.start]
+    }
 }
 
-fn main() {
+#[allow(dead_code)]
+struct PrefixSum2D<T = i64> {
+    pre: Vec<Vec<T>>,
+}
+#[allow(dead_code)]
+impl<T: Num> PrefixSum2D<T> {
+    fn new(a: &[Vec<T>]) -> Self {
+        let h = a.len();
+        let w = if h > 0 { a[0].len() } else { 0 };
+        let mut pre = vec![vec![T::zero(); w + 1]; h + 1];
+        for i in 0..h {
+            for j in 0..w {
+                pre[i + 1][j + 1] = pre[i][j + 1] + pre[i + 1][j] - pre[i][j] + a[i][j];
+            }
+        }
+        Self { pre }
+    }
+    // rows r.start..r.end, cols c.start..c.end
+    fn sum(&self, r: std::ops::Range<usize>, c: std::ops::Range<usize>) -> T {
+        if r.start >= r.end || c.start >= c.end {
+            return T::zero();
+        }
+        self.pre[r.end][c.end] - self.pre[r.start][c.end] - self.pre[r.end][c.start]
+            + self.pre[r.start][c.start]
+    }
+}
+
+// pred monotone (false..true) on [lo, hi], returns the first true (hi + 1 if none)
+#[allow(dead_code)]
+fn binary_search_answer<T: Num>(mut lo: T, mut hi: T, pred: impl Fn(T) -> bool) -> T {
+    if !pred(hi) {
+        return hi
+            .checked_add(T::one())
+            .expect("binary_search_answer: pred(hi) is false and hi + 1 overflows");
+    }
+    let two = T::one() + T::one();
+    while lo < hi {
+        let mid = match hi.checked_sub(lo) {
+            Some(d) => lo + d / two,
+            None => lo / two + hi / two,
+        };
+        if pred(mid) {
+            hi = mid;
+        } else {
+            lo = mid + T::one();
+        }
+    }
+    lo
+}
+// float version, fixed 100 iterations instead of an eps check (never loops forever)
+#[allow(dead_code)]
+fn binary_search_answer_f64(mut lo: f64, mut hi: f64, pred: impl Fn(f64) -> bool) -> f64 {
+    for _ in 0..100 {
+        let mid = (lo + hi) / 2.0;
+        if pred(mid) {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+    hi
+}
+
+// pairs i < j with a[i] > a[j] (= adjacent swaps needed to sort), merge sort O(n log n)
+#[allow(dead_code)]
+fn count_inversions<T: Ord + Clone>(a: &[T]) -> u64 {
+    fn rec<T: Ord + Clone>(a: &mut [T], buf: &mut Vec<T>) -> u64 {
+        let n = a.len();
+        if n <= 1 {
+            return 0;
+        }
+        let mid = n / 2;
+        let mut cnt = rec(&mut a[..mid], buf) + rec(&mut a[mid..], buf);
+        buf.clear();
+        let (mut i, mut j) = (0, mid);
+        while i < mid && j < n {
+            if a[j] < a[i] {
+                cnt += (mid - i) as u64;
+                buf.push(a[j].clone());
+                j += 1;
+            } else {
+                buf.push(a[i].clone());
+                i += 1;
+            }
+        }
+        buf.extend_from_slice(&a[i..mid]);
+        buf.extend_from_slice(&a[j..n]);
+        a.clone_from_slice(buf);
+        cnt
+    }
+    let mut v = a.to_vec();
+    rec(&mut v, &mut Vec::with_capacity(a.len()))
+}
+
+// kadane, best non-empty subarray (all negative -> the largest single element)
+#[allow(dead_code)]
+fn max_subarray_sum(a: &[i64]) -> (i64, std::ops::Range<usize>) {
+    assert!(!a.is_empty());
+    let (mut best, mut best_range) = (a[0], 0..1);
+    let (mut cur, mut start) = (0i64, 0);
+    for (i, &x) in a.iter().enumerate() {
+        if cur <= 0 {
+            cur = x;
+            start = i;
+        } else {
+            cur += x;
+        }
+        if cur > best {
+            best = cur;
+            best_range = start..i + 1;
+        }
+    }
+    (best, best_range)
+}
+// the range may run past a.len(), those indices wrap around (take them mod n)
+#[allow(dead_code)]
+fn max_subarray_sum_circular(a: &[i64]) -> (i64, std::ops::Range<usize>) {
+    let n = a.len();
+    let (mut best, mut range) = max_subarray_sum(a);
+    let total: i64 = a.iter().sum();
+    // wrapping = total - a proper middle part, which misses index 0 or index n - 1
+    for off in 0..2.min(n - 1) {
+        let neg: Vec<i64> = a[off..n - 1 + off].iter().map(|x| -x).collect();
+        let (m, r) = max_subarray_sum(&neg);
+        if total + m > best {
+            best = total + m;
+            range = r.end + off..r.start + off + n;
+        }
+    }
+    (best, range)
+}
+// best subarray with length >= k
+#[allow(dead_code)]
+fn max_subarray_sum_min_len(a: &[i64], k: usize) -> (i64, std::ops::Range<usize>) {
+    assert!(k >= 1 && k <= a.len());
+    let mut pre = vec![0i64; a.len() + 1];
+    for i in 0..a.len() {
+        pre[i + 1] = pre[i] + a[i];
+    }
+    let (mut best, mut range) = (i64::MIN, 0..0);
+    let mut min_at = 0;
+    for r in k..=a.len() {
+        if pre[r - k] < pre[min_at] {
+            min_at = r - k;
+        }
+        if pre[r] - pre[min_at] > best {
+            best = pre[r] - pre[min_at];
+            range = min_at..r;
+        }
+    }
+    (best, range)
+}
+
+// for every right end r, the longest window l..r+1 with ok(state) true.
+// add/remove update the shared state when r enters / l leaves, ok has to stay
+// true when shrinking a valid window. an empty window (l == r + 1) is always ok
+#[allow(dead_code)]
+fn two_pointers<S>(
+    n: usize,
+    state: &mut S,
+    mut add: impl FnMut(&mut S, usize),
+    mut remove: impl FnMut(&mut S, usize),
+    ok: impl Fn(&S) -> bool,
+) -> Vec<(usize, usize)> {
+    let mut res = Vec::with_capacity(n);
+    let mut l = 0;
+    for r in 0..n {
+        add(state, r);
+        while l <= r && !ok(state) {
+            remove(state, l);
+            l += 1;
+        }
+        res.push((l, r + 1));
+    }
+    res
+}
+
+// python's collections.Counter
+#[allow(dead_code)]
+fn counter<T: std::hash::Hash + Eq>(it: impl IntoIterator<Item = T>) -> HashMap<T, usize> {
+    let mut cnt = HashMap::new();
+    for x in it {
+        *cnt.entry(x).or_insert(0) += 1;
+    }
+    cnt
+}
+#[allow(dead_code)]
+fn counter_btree<T: Ord>(it: impl IntoIterator<Item = T>) -> BTreeMap<T, usize> {
+    let mut cnt = BTreeMap::new();
+    for x in it {
+        *cnt.entry(x).or_insert(0) += 1;
+    }
+    cnt
+}
+// k most frequent, ties broken by the smaller value so the output is deterministic
+#[allow(dead_code)]
+trait MostCommon<T> {
+    fn most_common(&self, k: usize) -> Vec<(T, usize)>;
+}
+fn top_k_counts<'a, T: Ord + Clone + 'a>(
+    it: impl Iterator<Item = (&'a T, &'a usize)>,
+    k: usize,
+) -> Vec<(T, usize)> {
+    let mut v: Vec<(T, usize)> = it.map(|(x, &c)| (x.clone(), c)).collect();
+    v.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    v.truncate(k);
+    v
+}
+impl<T: Ord + Clone + std::hash::Hash> MostCommon<T> for HashMap<T, usize> {
+    fn most_common(&self, k: usize) -> Vec<(T, usize)> {
+        top_k_counts(self.iter(), k)
+    }
+}
+impl<T: Ord + Clone> MostCommon<T> for BTreeMap<T, usize> {
+    fn most_common(&self, k: usize) -> Vec<(T, usize)> {
+        top_k_counts(self.iter(), k)
+    }
+}
+
+// run length encoding: [1, 1, 2, 1] -> [(1, 2), (2, 1), (1, 1)]
+#[allow(dead_code)]
+fn group_consecutive<T: PartialEq + Clone>(a: &[T]) -> Vec<(T, usize)> {
+    let mut res: Vec<(T, usize)> = Vec::new();
+    for x in a {
+        match res.last_mut() {
+            Some((y, c)) if y == x => *c += 1,
+            _ => res.push((x.clone(), 1)),
+        }
+    }
+    res
+}
+// a.len() has to be a multiple of k
+#[allow(dead_code)]
+fn chunks_exact_vec<T: Clone>(a: &[T], k: usize) -> Vec<Vec<T>> {
+    assert!(
+        k > 0 && a.len().is_multiple_of(k),
+        "length is not a multiple of k"
+    );
+    a.chunks_exact(k).map(|c| c.to_vec()).collect()
+}
+// like slice::split: elements matching pred are separators and dropped, empty parts kept
+#[allow(dead_code)]
+fn split_by<T: Clone>(a: &[T], pred: impl FnMut(&T) -> bool) -> Vec<Vec<T>> {
+    a.split(pred).map(|c| c.to_vec()).collect()
+}
+
+// indices that sort a (stable), a[idx[0]] is the smallest
+#[allow(dead_code)]
+fn argsort<T: Ord>(a: &[T]) -> Vec<usize> {
+    let mut idx: Vec<usize> = (0..a.len()).collect();
+    idx.sort_by(|&i, &j| a[i].cmp(&a[j]));
+    idx
+}
+// v becomes [v[p[0]], v[p[1]], ..], so apply_permutation(&mut v, &argsort(&v)) sorts v
+#[allow(dead_code)]
+fn apply_permutation<T>(v: &mut Vec<T>, p: &[usize]) {
+    assert_eq!(v.len(), p.len());
+    let mut old: Vec<Option<T>> = v.drain(..).map(Some).collect();
+    v.extend(p.iter().map(|&i| old[i].take().expect("not a permutation")));
+}
+// q[p[i]] = i
+#[allow(dead_code)]
+fn inverse_permutation(p: &[usize]) -> Vec<usize> {
+    let mut q = vec![0; p.len()];
+    for (i, &x) in p.iter().enumerate() {
+        q[x] = i;
+    }
+    q
+}
+// cycles of i -> p[i], fixed points included as 1-cycles
+#[allow(dead_code)]
+fn permutation_cycles(p: &[usize]) -> Vec<Vec<usize>> {
+    let mut seen = vec![false; p.len()];
+    let mut res = Vec::new();
+    for s in 0..p.len() {
+        if seen[s] {
+            continue;
+        }
+        let mut cyc = Vec::new();
+        let mut v = s;
+        while !seen[v] {
+            seen[v] = true;
+            cyc.push(v);
+            v = p[v];
+        }
+        res.push(cyc);
+    }
+    res
+}
+
+// all submasks of mask from mask down to 0 (the (sub - 1) & mask trick)
+#[allow(dead_code)]
+fn subsets_of(mask: u64) -> impl Iterator<Item = u64> {
+    let mut sub = Some(mask);
+    std::iter::from_fn(move || {
+        let cur = sub?;
+        sub = if cur == 0 {
+            None
+        } else {
+            Some((cur - 1) & mask)
+        };
+        Some(cur)
+    })
+}
+// every n-bit mask with exactly k bits set, increasing (gosper's hack)
+#[allow(dead_code)]
+fn masks_with_popcount(n: u32, k: u32) -> impl Iterator<Item = u64> {
+    assert!(n < 64);
+    let mut cur = if k > n { None } else { Some((1u64 << k) - 1) };
+    std::iter::from_fn(move || {
+        let x = cur?;
+        if x >> n != 0 {
+            return None;
+        }
+        cur = if x == 0 {
+            None
+        } else {
+            let c = x & x.wrapping_neg();
+            let r = x + c;
+            Some((((r ^ x) >> 2) / c) | r)
+        };
+        Some(x)
+    })
+}
+#[allow(dead_code)]
+fn has_bit(mask: u64, i: u32) -> bool {
+    (mask >> i) & 1 == 1
+}
+#[allow(dead_code)]
+fn popcount(mask: u64) -> u32 {
+    mask.count_ones()
+}
+// index of the lowest set bit, mask != 0
+#[allow(dead_code)]
+fn lowest_bit(mask: u64) -> u32 {
+    mask.trailing_zeros()
+}
+// index of the highest set bit, mask != 0
+#[allow(dead_code)]
+fn highest_bit(mask: u64) -> u32 {
+    63 - mask.leading_zeros()
+}
+
+// up, right, down, left
+const DX4: [isize; 4] = [-1, 0, 1, 0];
+const DY4: [isize; 4] = [0, 1, 0, -1];
+#[allow(dead_code)]
+const DX8: [isize; 8] = [-1, -1, 0, 1, 1, 1, 0, -1];
+#[allow(dead_code)]
+const DY8: [isize; 8] = [0, 1, 1, 1, 0, -1, -1, -1];
+
+fn grid_step(
+    r: usize,
+    c: usize,
+    h: usize,
+    w: usize,
+    dx: &'static [isize],
+    dy: &'static [isize],
+) -> impl Iterator<Item = (usize, usize)> {
+    dx.iter().zip(dy).filter_map(move |(&a, &b)| {
+        let nr = r.checked_add_signed(a)?;
+        let nc = c.checked_add_signed(b)?;
+        (nr < h && nc < w).then_some((nr, nc))
+    })
+}
+// in-bounds neighbours of (r, c) in an h x w grid
+#[allow(dead_code)]
+fn neighbors4(r: usize, c: usize, h: usize, w: usize) -> impl Iterator<Item = (usize, usize)> {
+    grid_step(r, c, h, w, &DX4, &DY4)
+}
+#[allow(dead_code)]
+fn neighbors8(r: usize, c: usize, h: usize, w: usize) -> impl Iterator<Item = (usize, usize)> {
+    grid_step(r, c, h, w, &DX8, &DY8)
+}
+// 4-connected component of start over cells where pred holds, bfs order
+#[allow(dead_code)]
+fn flood_fill<T>(
+    grid: &[Vec<T>],
+    start: (usize, usize),
+    pred: impl Fn(&T) -> bool,
+) -> Vec<(usize, usize)> {
+    let (h, w) = (grid.len(), grid[0].len());
+    let mut seen = vec![vec![false; w]; h];
+    let mut res = Vec::new();
+    if !pred(&grid[start.0][start.1]) {
+        return res;
+    }
+    seen[start.0][start.1] = true;
+    res.push(start);
+    let mut i = 0;
+    while i < res.len() {
+        let (r, c) = res[i];
+        i += 1;
+        for (nr, nc) in neighbors4(r, c, h, w) {
+            if !seen[nr][nc] && pred(&grid[nr][nc]) {
+                seen[nr][nc] = true;
+                res.push((nr, nc));
+            }
+        }
+    }
+    res
+}
+
+// same order as DX4/DY4, so `d as usize` indexes them
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum Dir {
+    U,
+    R,
+    D,
+    L,
+}
+#[allow(dead_code)]
+impl Dir {
+    const ALL: [Dir; 4] = [Dir::U, Dir::R, Dir::D, Dir::L];
+    fn turn_right(self) -> Dir {
+        Dir::ALL[(self as usize + 1) % 4]
+    }
+    fn turn_left(self) -> Dir {
+        Dir::ALL[(self as usize + 3) % 4]
+    }
+    fn reverse(self) -> Dir {
+        Dir::ALL[(self as usize + 2) % 4]
+    }
+    // (row delta, col delta), rows grow downwards
+    fn delta(self) -> (isize, isize) {
+        (DX4[self as usize], DY4[self as usize])
+    }
+    // next cell if it stays inside an h x w grid
+    fn step(self, r: usize, c: usize, h: usize, w: usize) -> Option<(usize, usize)> {
+        let (dr, dc) = self.delta();
+        let nr = r.checked_add_signed(dr)?;
+        let nc = c.checked_add_signed(dc)?;
+        (nr < h && nc < w).then_some((nr, nc))
+    }
+    // accepts UDLR, NESW and ^>v<
+    fn from_char(ch: char) -> Option<Dir> {
+        match ch.to_ascii_uppercase() {
+            'U' | 'N' | '^' => Some(Dir::U),
+            'R' | 'E' | '>' => Some(Dir::R),
+            'D' | 'S' | 'V' => Some(Dir::D),
+            'L' | 'W' | '<' => Some(Dir::L),
+            _ => None,
+        }
+    }
+}
+
+// works for non-square grids, h x w -> w x h
+#[allow(dead_code)]
+fn transpose<T: Clone>(g: &[Vec<T>]) -> Vec<Vec<T>> {
+    if g.is_empty() {
+        return vec![];
+    }
+    (0..g[0].len())
+        .map(|j| g.iter().map(|row| row[j].clone()).collect())
+        .collect()
+}
+#[allow(dead_code)]
+fn rotate_cw<T: Clone>(g: &[Vec<T>]) -> Vec<Vec<T>> {
+    let mut t = transpose(g);
+    for row in t.iter_mut() {
+        row.reverse();
+    }
+    t
+}
+#[allow(dead_code)]
+fn rotate_ccw<T: Clone>(g: &[Vec<T>]) -> Vec<Vec<T>> {
+    let mut t = transpose(g);
+    t.reverse();
+    t
+}
+// mirror left <-> right
+#[allow(dead_code)]
+fn flip_h<T: Clone>(g: &[Vec<T>]) -> Vec<Vec<T>> {
+    g.iter()
+        .map(|row| row.iter().rev().cloned().collect())
+        .collect()
+}
+// mirror top <-> bottom
+#[allow(dead_code)]
+fn flip_v<T: Clone>(g: &[Vec<T>]) -> Vec<Vec<T>> {
+    g.iter().rev().cloned().collect()
+}
+
+// intervals are half open [l, r), touching ones get merged too
+#[allow(dead_code)]
+fn merge_intervals(mut v: Vec<(i64, i64)>) -> Vec<(i64, i64)> {
+    v.sort();
+    let mut res: Vec<(i64, i64)> = Vec::new();
+    for (l, r) in v {
+        if l >= r {
+            continue;
+        }
+        match res.last_mut() {
+            Some(last) if l <= last.1 => last.1 = last.1.max(r),
+            _ => res.push((l, r)),
+        }
+    }
+    res
+}
+#[allow(dead_code)]
+fn total_covered_length(v: Vec<(i64, i64)>) -> i64 {
+    merge_intervals(v).iter().map(|(l, r)| r - l).sum()
+}
+// (max number of intervals covering one point, such a point), (0, 0) if none
+#[allow(dead_code)]
+fn max_overlap_point(v: &[(i64, i64)]) -> (usize, i64) {
+    let mut ev: Vec<(i64, i32)> = Vec::with_capacity(2 * v.len());
+    for &(l, r) in v {
+        if l < r {
+            ev.push((l, 1));
+            ev.push((r, -1));
+        }
+    }
+    // at the same x the -1 sorts first, [1, 3) and [3, 5) don't overlap
+    ev.sort();
+    let (mut cur, mut best, mut at) = (0i64, 0i64, 0);
+    for (x, d) in ev {
+        cur += d as i64;
+        if cur > best {
+            best = cur;
+            at = x;
+        }
+    }
+    (best as usize, at)
+}
+
+// closed = false: [l, r), so [1, 3) and [3, 5) may both be taken.
+// closed = true: [l, r], touching intervals conflict. returns indices into v
+#[allow(dead_code)]
+fn max_non_overlapping(v: &[(i64, i64)], closed: bool) -> Vec<usize> {
+    let mut idx: Vec<usize> = (0..v.len()).collect();
+    idx.sort_by_key(|&i| v[i].1);
+    let mut res = Vec::new();
+    let mut end: Option<i64> = None;
+    for i in idx {
+        let (l, r) = v[i];
+        let free = match end {
+            None => true,
+            Some(e) => l > e || (!closed && l == e),
+        };
+        if free {
+            res.push(i);
+            end = Some(r);
+        }
+    }
+    res
+}
+// fewest integer points so that every interval contains one (same closed flag).
+// an empty interval can't contain a point, so it's rejected
+#[allow(dead_code)]
+fn min_points_to_stab(v: &[(i64, i64)], closed: bool) -> Vec<i64> {
+    for &(l, r) in v {
+        assert!(
+            if closed { l <= r } else { l < r },
+            "min_points_to_stab: empty interval ({}, {})",
+            l,
+            r
+        );
+    }
+    let mut s = v.to_vec();
+    s.sort_by_key(|&(_, r)| r);
+    let mut res: Vec<i64> = Vec::new();
+    for (l, r) in s {
+        let last = if closed { r } else { r - 1 };
+        match res.last() {
+            Some(&p) if l <= p => {}
+            _ => res.push(last),
+        }
+    }
+    res
+}
+
+// f64 that can go in BinaryHeap / BTreeMap / sort, ordered by total_cmp
+// (so -0.0 < 0.0 and NaN sorts last)
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug, Default)]
+struct OrdF64(f64);
+impl PartialEq for OrdF64 {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == std::cmp::Ordering::Equal
+    }
+}
+impl Eq for OrdF64 {}
+impl PartialOrd for OrdF64 {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for OrdF64 {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+impl From<f64> for OrdF64 {
+    fn from(x: f64) -> Self {
+        OrdF64(x)
+    }
+}
+impl From<OrdF64> for f64 {
+    fn from(x: OrdF64) -> Self {
+        x.0
+    }
+}
+impl Display for OrdF64 {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+macro_rules! ord_f64_op {
+    ($($tr:ident $f:ident),*) => {
+        $(impl std::ops::$tr for OrdF64 {
+            type Output = OrdF64;
+            fn $f(self, rhs: OrdF64) -> OrdF64 {
+                OrdF64(self.0.$f(rhs.0))
+            }
+        })*
+    };
+}
+ord_f64_op!(Add add, Sub sub, Mul mul, Div div);
+impl std::ops::Neg for OrdF64 {
+    type Output = OrdF64;
+    fn neg(self) -> OrdF64 {
+        OrdF64(-self.0)
+    }
+}
+
+// jump k steps along v -> f[v] in O(log k), k up to 2^max_pow - 1 (max_pow = 60 for 1e18).
+// with_values also folds w[v] (the value of the step out of v) along the way
+#[allow(dead_code)]
+struct BinaryLifting<T> {
+    up: Vec<Vec<usize>>,
+    val: Vec<Vec<T>>,
+    op: fn(&T, &T) -> T,
+}
+#[allow(dead_code)]
+impl BinaryLifting<()> {
+    fn new(f: Vec<usize>, max_pow: usize) -> Self {
+        let n = f.len();
+        Self::with_values(f, vec![(); n], max_pow, |_, _| ())
+    }
+}
+#[allow(dead_code)]
+impl<T: Clone> BinaryLifting<T> {
+    fn with_values(f: Vec<usize>, w: Vec<T>, max_pow: usize, op: fn(&T, &T) -> T) -> Self {
+        assert!(
+            max_pow <= 64,
+            "k is a u64, max_pow = 64 already covers all of it"
+        );
+        let mut up = vec![f];
+        let mut val = vec![w];
+        for j in 1..max_pow {
+            let (pu, pv) = (&up[j - 1], &val[j - 1]);
+            let nu: Vec<usize> = (0..pu.len()).map(|v| pu[pu[v]]).collect();
+            let nv: Vec<T> = (0..pu.len()).map(|v| op(&pv[v], &pv[pu[v]])).collect();
+            up.push(nu);
+            val.push(nv);
+        }
+        Self { up, val, op }
+    }
+    fn apply(&self, mut v: usize, k: u64) -> usize {
+        // checked: a plain shift by 64 (max_pow = 64) would overflow
+        assert!(
+            k.checked_shr(self.up.len() as u32).unwrap_or(0) == 0,
+            "k needs a bigger max_pow"
+        );
+        for (j, up) in self.up.iter().enumerate() {
+            if (k >> j) & 1 == 1 {
+                v = up[v];
+            }
+        }
+        v
+    }
+    // (where k steps from v end up, init folded with every step value on the way)
+    fn fold(&self, mut v: usize, k: u64, init: T) -> (usize, T) {
+        assert!(
+            k.checked_shr(self.up.len() as u32).unwrap_or(0) == 0,
+            "k needs a bigger max_pow"
+        );
+        let mut acc = init;
+        for j in 0..self.up.len() {
+            if (k >> j) & 1 == 1 {
+                acc = (self.op)(&acc, &self.val[j][v]);
+                v = self.up[j][v];
+            }
+        }
+        (v, acc)
+    }
+}
+
+// monotonic stack, res[i] = nearest j with better(a[j], a[i]) on the right (rev) or
+// on the left of i, n if none
+fn nearest_by<T>(a: &[T], rev: bool, better: impl Fn(&T, &T) -> bool) -> Vec<usize> {
+    let n = a.len();
+    let mut res = vec![n; n];
+    let mut st: Vec<usize> = Vec::new();
+    let order: Vec<usize> = if rev {
+        (0..n).rev().collect()
+    } else {
+        (0..n).collect()
+    };
+    for i in order {
+        while let Some(&j) = st.last() {
+            if better(&a[j], &a[i]) {
+                break;
+            }
+            st.pop();
+        }
+        if let Some(&j) = st.last() {
+            res[i] = j;
+        }
+        st.push(i);
+    }
+    res
+}
+// first j > i with a[j] > a[i] (strictly), n if none
+#[allow(dead_code)]
+fn next_greater_indices<T: Ord>(a: &[T]) -> Vec<usize> {
+    nearest_by(a, true, |x, y| x > y)
+}
+// last j < i with a[j] > a[i], n if none
+#[allow(dead_code)]
+fn prev_greater<T: Ord>(a: &[T]) -> Vec<usize> {
+    nearest_by(a, false, |x, y| x > y)
+}
+#[allow(dead_code)]
+fn next_smaller<T: Ord>(a: &[T]) -> Vec<usize> {
+    nearest_by(a, true, |x, y| x < y)
+}
+#[allow(dead_code)]
+fn prev_smaller<T: Ord>(a: &[T]) -> Vec<usize> {
+    nearest_by(a, false, |x, y| x < y)
+}
+
+// boyer-moore vote + a second pass to check it really appears > n/2 times
+#[allow(dead_code)]
+fn majority_element<T: PartialEq + Clone>(a: &[T]) -> Option<T> {
+    let mut cand: Option<&T> = None;
+    let mut cnt = 0;
+    for x in a {
+        if cnt == 0 {
+            cand = Some(x);
+            cnt = 1;
+        } else if cand == Some(x) {
+            cnt += 1;
+        } else {
+            cnt -= 1;
+        }
+    }
+    let c = cand?;
+    (a.iter().filter(|&x| x == c).count() * 2 > a.len()).then(|| c.clone())
+}
+// every value appearing more than n/k times (at most k - 1 of them), misra-gries
+// with k - 1 counters then verified. O(nk)
+#[allow(dead_code)]
+fn frequent_elements<T: PartialEq + Clone>(a: &[T], k: usize) -> Vec<T> {
+    assert!(k >= 2);
+    let mut cands: Vec<(T, usize)> = Vec::with_capacity(k - 1);
+    for x in a {
+        if let Some(c) = cands.iter_mut().find(|c| &c.0 == x) {
+            c.1 += 1;
+        } else if cands.len() < k - 1 {
+            cands.push((x.clone(), 1));
+        } else {
+            for c in cands.iter_mut() {
+                c.1 -= 1;
+            }
+            cands.retain(|c| c.1 > 0);
+        }
+    }
+    cands
+        .into_iter()
+        .map(|(c, _)| c)
+        .filter(|c| a.iter().filter(|&x| x == c).count() * k > a.len())
+        .collect()
+}
+
+// res[i] = max(a[0..=i]) / max(a[i..]) etc, for "best prefix + best suffix" splits
+#[allow(dead_code)]
+fn prefix_max<T: Ord + Clone>(a: &[T]) -> Vec<T> {
+    scan_best(a.iter(), |x, y| x.max(y))
+}
+#[allow(dead_code)]
+fn prefix_min<T: Ord + Clone>(a: &[T]) -> Vec<T> {
+    scan_best(a.iter(), |x, y| x.min(y))
+}
+#[allow(dead_code)]
+fn suffix_max<T: Ord + Clone>(a: &[T]) -> Vec<T> {
+    let mut res = scan_best(a.iter().rev(), |x, y| x.max(y));
+    res.reverse();
+    res
+}
+#[allow(dead_code)]
+fn suffix_min<T: Ord + Clone>(a: &[T]) -> Vec<T> {
+    let mut res = scan_best(a.iter().rev(), |x, y| x.min(y));
+    res.reverse();
+    res
+}
+fn scan_best<'a, T: Clone + 'a>(it: impl Iterator<Item = &'a T>, pick: fn(T, T) -> T) -> Vec<T> {
+    let mut res: Vec<T> = Vec::new();
+    for x in it {
+        let v = match res.last() {
+            Some(b) => pick(b.clone(), x.clone()),
+            None => x.clone(),
+        };
+        res.push(v);
+    }
+    res
+}
+
+// slice::partition_point over the integers of [lo, hi): pred is true..true false..false,
+// returns the first x with pred(x) false (hi if none). safe for any i64 bounds
+#[allow(dead_code)]
+fn partition_point_range(mut lo: i64, mut hi: i64, pred: impl Fn(i64) -> bool) -> i64 {
+    while lo < hi {
+        let mid = lo + ((hi as i128 - lo as i128) / 2) as i64;
+        if pred(mid) {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    lo
+}
+
+// fixed size bitset, bit i lives in w[i / 64]
+#[allow(dead_code)]
+#[derive(Clone, Debug)]
+struct Bitset {
+    n: usize,
+    w: Vec<u64>,
+}
+#[allow(dead_code)]
+impl Bitset {
+    fn new(n: usize) -> Self {
+        Self {
+            n,
+            w: vec![0; n.div_ceil(64)],
+        }
+    }
+    fn set(&mut self, i: usize) {
+        self.w[i / 64] |= 1 << (i % 64);
+    }
+    fn get(&self, i: usize) -> bool {
+        (self.w[i / 64] >> (i % 64)) & 1 == 1
+    }
+    fn reset(&mut self, i: usize) {
+        self.w[i / 64] &= !(1 << (i % 64));
+    }
+    fn count_ones(&self) -> usize {
+        self.w.iter().map(|x| x.count_ones() as usize).sum()
+    }
+    // self |= o, same size
+    fn or_with(&mut self, o: &Bitset) {
+        for (a, b) in self.w.iter_mut().zip(&o.w) {
+            *a |= b;
+        }
+    }
+    // indices of the set bits in increasing order
+    fn ones(&self) -> impl Iterator<Item = usize> + '_ {
+        self.w.iter().enumerate().flat_map(|(i, &x)| {
+            let mut x = x;
+            std::iter::from_fn(move || {
+                if x == 0 {
+                    return None;
+                }
+                let b = x.trailing_zeros() as usize;
+                x &= x - 1;
+                Some(i * 64 + b)
+            })
+        })
+    }
+    // self |= self << k, bits past n are dropped
+    fn or_shl(&mut self, k: usize) {
+        let (ws, bs) = (k / 64, k % 64);
+        for i in (ws..self.w.len()).rev() {
+            let mut x = self.w[i - ws] << bs;
+            if bs > 0 && i > ws {
+                x |= self.w[i - ws - 1] >> (64 - bs);
+            }
+            self.w[i] |= x;
+        }
+        if !self.n.is_multiple_of(64) {
+            let last = self.w.len() - 1;
+            self.w[last] &= (1 << (self.n % 64)) - 1;
+        }
+    }
+}
+
+// best total value with total weight <= cap, each item once. O(n * cap)
+#[allow(dead_code)]
+fn knapsack_01(w: &[usize], v: &[i64], cap: usize) -> i64 {
+    assert_eq!(w.len(), v.len(), "weights and values differ in length");
+    let mut dp = vec![0i64; cap + 1];
+    for (&wi, &vi) in w.iter().zip(v) {
+        for c in (wi..=cap).rev() {
+            dp[c] = dp[c].max(dp[c - wi] + vi);
+        }
+    }
+    dp[cap]
+}
+// same, each item any number of times
+#[allow(dead_code)]
+fn unbounded_knapsack(w: &[usize], v: &[i64], cap: usize) -> i64 {
+    assert_eq!(w.len(), v.len(), "weights and values differ in length");
+    let mut dp = vec![0i64; cap + 1];
+    for (&wi, &vi) in w.iter().zip(v) {
+        for c in wi..=cap {
+            dp[c] = dp[c].max(dp[c - wi] + vi);
+        }
+    }
+    dp[cap]
+}
+// ways to pay target (order of coins doesn't matter), mod MOD. a 0 coin would make
+// every count infinite, so it's rejected
+#[allow(dead_code)]
+fn coin_change_count(coins: &[usize], target: usize) -> u64 {
+    assert!(
+        coins.iter().all(|&c| c > 0),
+        "coin_change_count: 0-valued coin"
+    );
+    let mut dp = vec![0u64; target + 1];
+    dp[0] = 1;
+    for &c in coins {
+        for s in c..=target {
+            dp[s] = (dp[s] + dp[s - c]) % MOD;
+        }
+    }
+    dp[target]
+}
+// fewest coins summing to target, None if impossible
+#[allow(dead_code)]
+fn coin_change_min(coins: &[usize], target: usize) -> Option<usize> {
+    let mut dp = vec![usize::MAX; target + 1];
+    dp[0] = 0;
+    for s in 1..=target {
+        for &c in coins {
+            if c <= s && dp[s - c] != usize::MAX {
+                dp[s] = dp[s].min(dp[s - c] + 1);
+            }
+        }
+    }
+    (dp[target] != usize::MAX).then_some(dp[target])
+}
+// bit s set <=> some subset of w sums to s (s <= cap), O(n * cap / 64)
+#[allow(dead_code)]
+fn subset_sums(w: &[usize], cap: usize) -> Bitset {
+    let mut b = Bitset::new(cap + 1);
+    b.set(0);
+    for &x in w {
+        if x <= cap {
+            b.or_shl(x);
+        }
+    }
+    b
+}
+// bit s set <=> s is payable with unlimited coins (s <= cap)
+// shifting by c, 2c, 4c, .. allows any multiple of c
+#[allow(dead_code)]
+fn coin_sums(coins: &[usize], cap: usize) -> Bitset {
+    let mut b = Bitset::new(cap + 1);
+    b.set(0);
+    for &c in coins {
+        let mut k = c;
+        while k > 0 && k <= cap {
+            b.or_shl(k);
+            k *= 2;
+        }
+    }
+    b
+}
+
+// first index where a and b differ, None if one is a prefix of the other
+// (including equal). a.len().min(b.len()) is then the mismatch point
+#[allow(dead_code)]
+fn first_difference<T: PartialEq>(a: &[T], b: &[T]) -> Option<usize> {
+    let k = common_prefix_len(a, b);
+    (k < a.len().min(b.len())).then_some(k)
+}
+#[allow(dead_code)]
+fn common_prefix_len<T: PartialEq>(a: &[T], b: &[T]) -> usize {
+    a.iter().zip(b).take_while(|(x, y)| x == y).count()
+}
+// a can be obtained from b by deleting elements, greedy O(|b|)
+#[allow(dead_code)]
+fn is_subsequence<T: PartialEq>(a: &[T], b: &[T]) -> bool {
+    let mut i = 0;
+    for x in b {
+        if i < a.len() && a[i] == *x {
+            i += 1;
+        }
+    }
+    i == a.len()
+}
+
+// coordinate compression: (ranks, sorted distinct values), a[i] == vals[ranks[i]]
+#[allow(dead_code)]
+fn compress<T: Ord + Clone>(a: &[T]) -> (Vec<usize>, Vec<T>) {
+    let mut vals = a.to_vec();
+    vals.sort();
+    vals.dedup();
+    let ranks = a.iter().map(|x| vals.binary_search(x).unwrap()).collect();
+    (ranks, vals)
+}
+
+// res[i] = #{j : j < i, a[j] < a[i]} (before) or j > i (after), strict compare.
+// greater: a[j] > a[i]. O(n log n)
+#[allow(dead_code)]
+fn count_compare<T: Ord + Clone>(a: &[T], after: bool, greater: bool) -> Vec<usize> {
+    let (r, vals) = compress(a);
+    let mut fw = Fenwick::<usize>::new(vals.len());
+    let mut res = vec![0; a.len()];
+    let order: Vec<usize> = if after {
+        (0..a.len()).rev().collect()
+    } else {
+        (0..a.len()).collect()
+    };
+    for (seen, &i) in order.iter().enumerate() {
+        let less = fw.sum(r[i]);
+        let le = fw.sum(r[i] + 1);
+        res[i] = if greater { seen - le } else { less };
+        fw.add(r[i], 1);
+    }
+    res
+}
+#[allow(dead_code)]
+fn count_smaller_before<T: Ord + Clone>(a: &[T]) -> Vec<usize> {
+    count_compare(a, false, false)
+}
+#[allow(dead_code)]
+fn count_greater_before<T: Ord + Clone>(a: &[T]) -> Vec<usize> {
+    count_compare(a, false, true)
+}
+#[allow(dead_code)]
+fn count_smaller_after<T: Ord + Clone>(a: &[T]) -> Vec<usize> {
+    count_compare(a, true, false)
+}
+#[allow(dead_code)]
+fn count_greater_after<T: Ord + Clone>(a: &[T]) -> Vec<usize> {
+    count_compare(a, true, true)
+}
+
+// adjacency list, adj[u] = (v, w). unweighted input gets w = 1,
+// undirected edges are stored in both directions
+#[allow(dead_code)]
+#[derive(Clone, Debug)]
+struct Graph {
+    n: usize,
+    adj: Vec<Vec<(usize, i64)>>,
+}
+#[allow(dead_code)]
+impl Graph {
+    fn new(n: usize) -> Self {
+        Self {
+            n,
+            adj: vec![Vec::new(); n],
+        }
+    }
+    fn add_edge(&mut self, u: usize, v: usize, w: i64) {
+        self.adj[u].push((v, w));
+    }
+    fn add_undirected(&mut self, u: usize, v: usize, w: i64) {
+        self.adj[u].push((v, w));
+        self.adj[v].push((u, w));
+    }
+    // tree given as "p_2 .. p_n": p[i] is the parent of vertex i + 1 (0-based,
+    // read it with next_vec_usize1), root is 0
+    fn from_parent_array(p: &[usize]) -> Self {
+        let mut g = Self::new(p.len() + 1);
+        for (i, &par) in p.iter().enumerate() {
+            g.add_undirected(par, i + 1, 1);
+        }
+        g
+    }
+    // reads n - 1 lines "u v" (1-based)
+    fn tree_from_edges(scan: &mut Scanner, n: usize) -> Self {
+        let mut g = Self::new(n);
+        for (u, v) in scan.next_pairs_usize1(n.saturating_sub(1)) {
+            g.add_undirected(u, v, 1);
+        }
+        g
+    }
+    // reads n - 1 lines "u v w" (1-based vertices)
+    fn tree_from_weighted_edges(scan: &mut Scanner, n: usize) -> Self {
+        let mut g = Self::new(n);
+        for _ in 1..n {
+            let (u, v, w) = (scan.next_usize1(), scan.next_usize1(), scan.next());
+            g.add_undirected(u, v, w);
+        }
+        g
+    }
+}
+
+// usize::MAX marks "unreached" in dist / tin and "no parent" in parent
+#[allow(dead_code)]
+#[derive(Clone, Debug)]
+struct BfsResult {
+    dist: Vec<usize>,
+    parent: Vec<usize>,
+    order: Vec<usize>,
+}
+// order is the preorder, subtree of v is order[tin[v]..tout[v]]
+#[allow(dead_code)]
+#[derive(Clone, Debug)]
+struct DfsResult {
+    parent: Vec<usize>,
+    order: Vec<usize>,
+    tin: Vec<usize>,
+    tout: Vec<usize>,
+}
+#[allow(dead_code)]
+impl Graph {
+    // edge count distances (weights ignored), parents and visiting order
+    fn bfs_full(&self, src: usize) -> BfsResult {
+        let mut dist = vec![usize::MAX; self.n];
+        let mut parent = vec![usize::MAX; self.n];
+        let mut order = vec![src];
+        dist[src] = 0;
+        let mut head = 0;
+        while head < order.len() {
+            let u = order[head];
+            head += 1;
+            for &(v, _) in &self.adj[u] {
+                if dist[v] == usize::MAX {
+                    dist[v] = dist[u] + 1;
+                    parent[v] = u;
+                    order.push(v);
+                }
+            }
+        }
+        BfsResult {
+            dist,
+            parent,
+            order,
+        }
+    }
+    // iterative, so a path of 1e6 vertices doesn't blow the stack
+    fn dfs_full(&self, root: usize) -> DfsResult {
+        let mut parent = vec![usize::MAX; self.n];
+        let mut tin = vec![usize::MAX; self.n];
+        let mut tout = vec![usize::MAX; self.n];
+        let mut order = Vec::new();
+        // (vertex, next adj index to look at)
+        let mut stack = vec![(root, 0)];
+        tin[root] = 0;
+        order.push(root);
+        while let Some(&mut (u, ref mut i)) = stack.last_mut() {
+            if let Some(&(v, _)) = self.adj[u].get(*i) {
+                *i += 1;
+                if tin[v] == usize::MAX {
+                    parent[v] = u;
+                    tin[v] = order.len();
+                    order.push(v);
+                    stack.push((v, 0));
+                }
+            } else {
+                tout[u] = order.len();
+                stack.pop();
+            }
+        }
+        DfsResult {
+            parent,
+            order,
+            tin,
+            tout,
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl Graph {
+    // weighted distance from root along the tree paths, O(n) instead of dijkstra
+    fn tree_distances(&self, root: usize) -> Vec<i64> {
+        let mut d = vec![0; self.n];
+        let mut seen = vec![false; self.n];
+        let mut stack = vec![root];
+        seen[root] = true;
+        while let Some(u) = stack.pop() {
+            for &(v, w) in &self.adj[u] {
+                if !seen[v] {
+                    seen[v] = true;
+                    d[v] = d[u] + w;
+                    stack.push(v);
+                }
+            }
+        }
+        d
+    }
+}
+
+// binary lifting lca on a tree, up[k][v] = 2^k-th ancestor (root maps to itself).
+// with values, every jump also carries the fold of the 2^k edges it skips, in both
+// directions (agg_up walks towards the root, agg_down away from it), so op only has
+// to be associative: sums, min / max, matrix products, function composition
+#[allow(dead_code)]
+#[derive(Clone, Debug)]
+struct Lca<T = i64> {
+    up: Vec<Vec<usize>>,
+    depth: Vec<usize>,
+    dist: Vec<i64>,
+    agg_up: Vec<Vec<T>>,
+    agg_down: Vec<Vec<T>>,
+    op: fn(T, T) -> T,
+    id: T,
+}
+#[allow(dead_code)]
+impl Lca {
+    fn new(g: &Graph, root: usize) -> Self {
+        Self::build(g, root, |a, _| a, 0)
+    }
+    // also folds edge weights along paths: op = max / min / + with its identity id
+    // (i64::MIN / i64::MAX / 0)
+    fn with_op(g: &Graph, root: usize, op: fn(i64, i64) -> i64, id: i64) -> Self {
+        Self::with_values(g, root, |_, _, w| w, op, id)
+    }
+}
+#[allow(dead_code)]
+impl<T: Clone> Lca<T> {
+    fn build(g: &Graph, root: usize, op: fn(T, T) -> T, id: T) -> Self {
+        let n = g.n;
+        let mut log = 1;
+        while (1 << log) < n {
+            log += 1;
+        }
+        let mut par = vec![root; n];
+        let mut depth = vec![0; n];
+        let mut dist = vec![0; n];
+        let mut seen = vec![false; n];
+        let mut stack = vec![root];
+        seen[root] = true;
+        while let Some(u) = stack.pop() {
+            for &(v, w) in &g.adj[u] {
+                if !seen[v] {
+                    seen[v] = true;
+                    par[v] = u;
+                    depth[v] = depth[u] + 1;
+                    dist[v] = dist[u] + w;
+                    stack.push(v);
+                }
+            }
+        }
+        let mut up = vec![par];
+        for k in 1..log {
+            let row = (0..n).map(|v| up[k - 1][up[k - 1][v]]).collect();
+            up.push(row);
+        }
+        Self {
+            up,
+            depth,
+            dist,
+            agg_up: Vec::new(),
+            agg_down: Vec::new(),
+            op,
+            id,
+        }
+    }
+    // val(v, parent, w) is the value of the edge from v up to its parent, e.g.
+    // |_, _, w| w for the weight or |v, _, _| f[v] for a per-vertex function
+    fn with_values(
+        g: &Graph,
+        root: usize,
+        val: impl Fn(usize, usize, i64) -> T,
+        op: fn(T, T) -> T,
+        id: T,
+    ) -> Self {
+        let mut l = Self::build(g, root, op, id);
+        let n = g.n;
+        let base: Vec<T> = (0..n)
+            .map(|v| {
+                let p = l.up[0][v];
+                if v == root {
+                    l.id.clone()
+                } else {
+                    val(v, p, l.dist[v] - l.dist[p])
+                }
+            })
+            .collect();
+        l.agg_up = vec![base.clone()];
+        l.agg_down = vec![base];
+        for k in 1..l.up.len() {
+            let (au, ad, up) = (&l.agg_up[k - 1], &l.agg_down[k - 1], &l.up[k - 1]);
+            let ru = (0..n)
+                .map(|v| op(au[v].clone(), au[up[v]].clone()))
+                .collect();
+            let rd = (0..n)
+                .map(|v| op(ad[up[v]].clone(), ad[v].clone()))
+                .collect();
+            l.agg_up.push(ru);
+            l.agg_down.push(rd);
+        }
+        l
+    }
+    fn kth_ancestor(&self, mut v: usize, k: usize) -> usize {
+        for (i, row) in self.up.iter().enumerate() {
+            if (k >> i) & 1 == 1 {
+                v = row[v];
+            }
+        }
+        v
+    }
+    fn lca(&self, u: usize, v: usize) -> usize {
+        let (mut u, mut v) = if self.depth[u] < self.depth[v] {
+            (v, u)
+        } else {
+            (u, v)
+        };
+        u = self.kth_ancestor(u, self.depth[u] - self.depth[v]);
+        if u == v {
+            return u;
+        }
+        for row in self.up.iter().rev() {
+            if row[u] != row[v] {
+                u = row[u];
+                v = row[v];
+            }
+        }
+        self.up[0][u]
+    }
+    // number of edges between u and v
+    fn path_edges(&self, u: usize, v: usize) -> usize {
+        self.depth[u] + self.depth[v] - 2 * self.depth[self.lca(u, v)]
+    }
+    // sum of edge weights between u and v
+    fn tree_path_length(&self, u: usize, v: usize) -> i64 {
+        self.dist[u] + self.dist[v] - 2 * self.dist[self.lca(u, v)]
+    }
+    // op over the first k edges going up from v, nearest first (k <= depth[v]).
+    // with functions as values: apply f[v], then f[parent], ... k times
+    fn fold_up(&self, mut v: usize, k: usize) -> T {
+        assert!(
+            !self.agg_up.is_empty(),
+            "build the Lca with with_op / with_values to fold paths"
+        );
+        let mut res = self.id.clone();
+        for (i, row) in self.agg_up.iter().enumerate() {
+            if (k >> i) & 1 == 1 {
+                res = (self.op)(res, row[v].clone());
+                v = self.up[i][v];
+            }
+        }
+        res
+    }
+    // op over the edge values in the order they're walked from u to v (id if u == v),
+    // e.g. the max edge on the path for "can this edge replace one in the MST"
+    fn fold_path(&self, u: usize, v: usize) -> T {
+        let c = self.lca(u, v);
+        let left = self.fold_up(u, self.depth[u] - self.depth[c]);
+        let mut right = self.id.clone();
+        let mut x = v;
+        for (k, row) in self.agg_down.iter().enumerate() {
+            if ((self.depth[v] - self.depth[c]) >> k) & 1 == 1 {
+                right = (self.op)(row[x].clone(), right);
+                x = self.up[k][x];
+            }
+        }
+        (self.op)(left, right)
+    }
+}
+
+// "next free slot": find(i) is the smallest j >= i not removed yet (n if none).
+// each removed index points to the right, with path compression
+#[allow(dead_code)]
+#[derive(Clone, Debug)]
+struct IntervalDsu {
+    nxt: Vec<usize>,
+}
+#[allow(dead_code)]
+impl IntervalDsu {
+    fn new(n: usize) -> Self {
+        Self {
+            nxt: (0..=n).collect(),
+        }
+    }
+    fn find(&mut self, i: usize) -> usize {
+        let mut r = i;
+        while self.nxt[r] != r {
+            r = self.nxt[r];
+        }
+        let mut i = i;
+        while self.nxt[i] != r {
+            let next = self.nxt[i];
+            self.nxt[i] = r;
+            i = next;
+        }
+        r
+    }
+    fn remove(&mut self, i: usize) {
+        if i + 1 < self.nxt.len() {
+            self.nxt[i] = i + 1;
+        }
+    }
+    // removes every free index in [l, r) and returns them, amortized O(r - l + removed)
+    fn take_range(&mut self, l: usize, r: usize) -> Vec<usize> {
+        let mut res = Vec::new();
+        let mut i = self.find(l);
+        while i < r {
+            res.push(i);
+            self.remove(i);
+            i = self.find(i + 1);
+        }
+        res
+    }
+}
+
+// partially persistent union find: the i-th call to unite happens at time i (1-based),
+// same(x, y, t) answers for the state right after the t-th call (t = 0 is the start).
+// union by size and no path compression, so old links stay valid, O(log n) per query
+#[allow(dead_code)]
+#[derive(Clone, Debug)]
+struct TimeDsu {
+    par: Vec<usize>,
+    size: Vec<usize>,
+    // time at which v stopped being a root
+    at: Vec<usize>,
+    now: usize,
+}
+#[allow(dead_code)]
+impl TimeDsu {
+    fn new(n: usize) -> Self {
+        Self {
+            par: (0..n).collect(),
+            size: vec![1; n],
+            at: vec![usize::MAX; n],
+            now: 0,
+        }
+    }
+    // root of x at time t
+    fn find(&self, mut x: usize, t: usize) -> usize {
+        while self.at[x] <= t {
+            x = self.par[x];
+        }
+        x
+    }
+    // counts as a time step even when x and y are already joined
+    fn unite(&mut self, x: usize, y: usize) -> bool {
+        self.now += 1;
+        let (mut x, mut y) = (self.find(x, self.now), self.find(y, self.now));
+        if x == y {
+            return false;
+        }
+        if self.size[x] < self.size[y] {
+            std::mem::swap(&mut x, &mut y);
+        }
+        self.par[y] = x;
+        self.at[y] = self.now;
+        self.size[x] += self.size[y];
+        true
+    }
+    fn same(&self, x: usize, y: usize, t: usize) -> bool {
+        self.find(x, t) == self.find(y, t)
+    }
+    // first time x and y are connected, None if they still aren't
+    fn connected_since(&self, x: usize, y: usize) -> Option<usize> {
+        if !self.same(x, y, self.now) {
+            return None;
+        }
+        let (mut lo, mut hi) = (0, self.now);
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            if self.same(x, y, mid) {
+                hi = mid;
+            } else {
+                lo = mid + 1;
+            }
+        }
+        Some(lo)
+    }
+}
+
+// square matrices mod MOD
+#[allow(dead_code)]
+type Matrix = Vec<Vec<u64>>;
+#[allow(dead_code)]
+fn mat_identity(n: usize) -> Matrix {
+    (0..n)
+        .map(|i| (0..n).map(|j| (i == j) as u64).collect())
+        .collect()
+}
+#[allow(dead_code)]
+fn mat_mul(a: &Matrix, b: &Matrix) -> Matrix {
+    assert_eq!(a[0].len(), b.len(), "mat_mul: dimension mismatch");
+    let mut c = vec![vec![0; b[0].len()]; a.len()];
+    for i in 0..a.len() {
+        for k in 0..b.len() {
+            if a[i][k] == 0 {
+                continue;
+            }
+            for j in 0..b[0].len() {
+                c[i][j] = (c[i][j] + a[i][k] * b[k][j]) % MOD;
+            }
+        }
+    }
+    c
+}
+#[allow(dead_code)]
+fn mat_vec_mul(a: &Matrix, v: &[u64]) -> Vec<u64> {
+    assert_eq!(a[0].len(), v.len(), "mat_vec_mul: dimension mismatch");
+    a.iter()
+        .map(|row| row.iter().zip(v).fold(0, |s, (&x, &y)| (s + x * y) % MOD))
+        .collect()
+}
+#[allow(dead_code)]
+fn mat_pow(a: &Matrix, mut k: u64) -> Matrix {
+    assert_eq!(a.len(), a[0].len(), "mat_pow: matrix is not square");
+    let mut res = mat_identity(a.len());
+    let mut base = a.clone();
+    while k > 0 {
+        if k & 1 == 1 {
+            res = mat_mul(&res, &base);
+        }
+        base = mat_mul(&base, &base);
+        k >>= 1;
+    }
+    res
+}
+// dp state after k steps of new[i] = sum_j t[i][j] * old[j], O(d^3 log k)
+// fibonacci: t = [[1, 1], [1, 0]], init = [f1, f0]
+#[allow(dead_code)]
+fn linear_dp_pow(t: &Matrix, init: &[u64], k: u64) -> Vec<u64> {
+    assert_eq!(
+        t.len(),
+        init.len(),
+        "linear_dp_pow: transition is not d x d"
+    );
+    mat_vec_mul(&mat_pow(t, k), init)
+}
+
+// polynomial rolling hash mod 2^61 - 1. a fixed base is easy to break with anti-hash
+// tests, so pick one per run with mersenne_base() and share it between strings
+// that get compared
+#[allow(dead_code)]
+const MERSENNE: u64 = (1 << 61) - 1;
+#[allow(dead_code)]
+fn mersenne_mul(a: u64, b: u64) -> u64 {
+    // 2^61 = 1, so fold the high part back onto the low 61 bits
+    let x = a as u128 * b as u128;
+    let r = (x >> 61) as u64 + (x as u64 & MERSENNE);
+    if r >= MERSENNE {
+        r - MERSENNE
+    } else {
+        r
+    }
+}
+#[allow(dead_code)]
+fn mersenne_base() -> u64 {
+    Rng::new().next_u64() % (MERSENNE - 1000) + 500
+}
+#[allow(dead_code)]
+#[derive(Clone, Debug)]
+struct MersenneHash {
+    pre: Vec<u64>,
+    pw: Vec<u64>,
+}
+#[allow(dead_code)]
+impl MersenneHash {
+    fn new(s: &[u8], base: u64) -> Self {
+        let mut pre = vec![0; s.len() + 1];
+        let mut pw = vec![1; s.len() + 1];
+        for (i, &c) in s.iter().enumerate() {
+            let h = mersenne_mul(pre[i], base) + c as u64 + 1;
+            pre[i + 1] = if h >= MERSENNE { h - MERSENNE } else { h };
+            pw[i + 1] = mersenne_mul(pw[i], base);
+        }
+        Self { pre, pw }
+    }
+    // hash of s[l..r]
+    fn get(&self, l: usize, r: usize) -> u64 {
+        let sub = mersenne_mul(self.pre[l], self.pw[r - l]);
+        if self.pre[r] >= sub {
+            self.pre[r] - sub
+        } else {
+            self.pre[r] + MERSENNE - sub
+        }
+    }
+}
+
+// sum of the k largest (largest = true) or k smallest values of a multiset under
+// insert / erase, O(log n) each. fewer than k values: sum of all of them
+#[allow(dead_code)]
+#[derive(Clone, Debug)]
+struct TopKSum {
+    k: usize,
+    sign: i64,
+    // values are stored times sign, top holds the k largest of those
+    top: BTreeMap<i64, usize>,
+    rest: BTreeMap<i64, usize>,
+    top_n: usize,
+    rest_n: usize,
+    sum: i64,
+}
+#[allow(dead_code)]
+impl TopKSum {
+    fn new(k: usize, largest: bool) -> Self {
+        Self {
+            k,
+            sign: if largest { 1 } else { -1 },
+            top: BTreeMap::new(),
+            rest: BTreeMap::new(),
+            top_n: 0,
+            rest_n: 0,
+            sum: 0,
+        }
+    }
+    fn add(m: &mut BTreeMap<i64, usize>, x: i64) {
+        *m.entry(x).or_insert(0) += 1;
+    }
+    fn del(m: &mut BTreeMap<i64, usize>, x: i64) -> bool {
+        match m.get_mut(&x) {
+            Some(c) if *c > 1 => *c -= 1,
+            Some(_) => {
+                m.remove(&x);
+            }
+            None => return false,
+        }
+        true
+    }
+    fn move_to_top(&mut self, x: i64) {
+        Self::del(&mut self.rest, x);
+        Self::add(&mut self.top, x);
+        self.rest_n -= 1;
+        self.top_n += 1;
+        self.sum += x;
+    }
+    fn move_to_rest(&mut self, x: i64) {
+        Self::del(&mut self.top, x);
+        Self::add(&mut self.rest, x);
+        self.top_n -= 1;
+        self.rest_n += 1;
+        self.sum -= x;
+    }
+    fn fix(&mut self) {
+        while self.top_n < self.k && self.rest_n > 0 {
+            let x = *self.rest.keys().next_back().unwrap();
+            self.move_to_top(x);
+        }
+        while self.top_n > self.k {
+            let x = *self.top.keys().next().unwrap();
+            self.move_to_rest(x);
+        }
+        while let (Some(&r), Some(&t)) = (self.rest.keys().next_back(), self.top.keys().next()) {
+            if r <= t {
+                break;
+            }
+            self.move_to_top(r);
+            self.move_to_rest(t);
+        }
+    }
+    fn insert(&mut self, x: i64) {
+        Self::add(&mut self.rest, x * self.sign);
+        self.rest_n += 1;
+        self.fix();
+    }
+    // false if x wasn't there
+    fn erase(&mut self, x: i64) -> bool {
+        let y = x * self.sign;
+        if Self::del(&mut self.rest, y) {
+            self.rest_n -= 1;
+        } else if Self::del(&mut self.top, y) {
+            self.top_n -= 1;
+            self.sum -= y;
+        } else {
+            return false;
+        }
+        self.fix();
+        true
+    }
+    fn sum(&self) -> i64 {
+        self.sum * self.sign
+    }
+    fn len(&self) -> usize {
+        self.top_n + self.rest_n
+    }
+}
+
+// max heap with deletion: removed values wait in a second heap and are dropped once
+// they reach the top. only remove values that are actually in the heap.
+// wrap in Reverse for a min heap
+#[allow(dead_code)]
+#[derive(Clone, Debug)]
+struct LazyHeap<T: Ord> {
+    heap: std::collections::BinaryHeap<T>,
+    removed: std::collections::BinaryHeap<T>,
+}
+#[allow(dead_code)]
+impl<T: Ord> LazyHeap<T> {
+    fn new() -> Self {
+        Self {
+            heap: std::collections::BinaryHeap::new(),
+            removed: std::collections::BinaryHeap::new(),
+        }
+    }
+    fn push(&mut self, x: T) {
+        self.heap.push(x);
+    }
+    fn remove(&mut self, x: T) {
+        self.removed.push(x);
+    }
+    fn clean(&mut self) {
+        while let (Some(a), Some(b)) = (self.heap.peek(), self.removed.peek()) {
+            if a != b {
+                break;
+            }
+            self.heap.pop();
+            self.removed.pop();
+        }
+    }
+    fn peek(&mut self) -> Option<&T> {
+        self.clean();
+        self.heap.peek()
+    }
+    fn pop(&mut self) -> Option<T> {
+        self.clean();
+        self.heap.pop()
+    }
+    fn len(&self) -> usize {
+        self.heap.len() - self.removed.len()
+    }
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+// BTreeMap<T, count> that never keeps zero counts around
+#[allow(dead_code)]
+#[derive(Clone, Debug)]
+struct MultiSet<T: Ord> {
+    map: BTreeMap<T, usize>,
+    len: usize,
+}
+#[allow(dead_code)]
+impl<T: Ord + Clone> MultiSet<T> {
+    fn new() -> Self {
+        Self {
+            map: BTreeMap::new(),
+            len: 0,
+        }
+    }
+    fn insert(&mut self, x: T) {
+        *self.map.entry(x).or_insert(0) += 1;
+        self.len += 1;
+    }
+    // false if x wasn't there
+    fn remove_one(&mut self, x: &T) -> bool {
+        match self.map.get_mut(x) {
+            Some(c) if *c > 1 => *c -= 1,
+            Some(_) => {
+                self.map.remove(x);
+            }
+            None => return false,
+        }
+        self.len -= 1;
+        true
+    }
+    fn count(&self, x: &T) -> usize {
+        self.map.get(x).copied().unwrap_or(0)
+    }
+    fn contains(&self, x: &T) -> bool {
+        self.map.contains_key(x)
+    }
+    fn min(&self) -> Option<&T> {
+        self.map.keys().next()
+    }
+    fn max(&self) -> Option<&T> {
+        self.map.keys().next_back()
+    }
+    fn pop_min(&mut self) -> Option<T> {
+        let x = self.min()?.clone();
+        self.remove_one(&x);
+        Some(x)
+    }
+    fn pop_max(&mut self) -> Option<T> {
+        let x = self.max()?.clone();
+        self.remove_one(&x);
+        Some(x)
+    }
+    // every element in the range, repeated count times, in order
+    fn range<R: std::ops::RangeBounds<T>>(&self, r: R) -> impl Iterator<Item = &T> + '_ {
+        self.map.range(r).flat_map(|(x, &c)| (0..c).map(move |_| x))
+    }
+    fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+        self.range(..)
+    }
+    fn len(&self) -> usize {
+        self.len
+    }
+    fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+// offline "how many points in each rectangle". rectangles are half open,
+// [x1, x2) x [y1, y2). sweep over x with a Fenwick over compressed y,
+// O((n + q) log n)
+#[allow(dead_code)]
+#[derive(Clone, Debug, Default)]
+struct RectangleCounter {
+    pts: Vec<(i64, i64)>,
+    rects: Vec<(i64, i64, i64, i64)>,
+}
+#[allow(dead_code)]
+impl RectangleCounter {
+    fn new() -> Self {
+        Self::default()
+    }
+    fn add_point(&mut self, x: i64, y: i64) {
+        self.pts.push((x, y));
+    }
+    // returns the query index
+    fn add_rect(&mut self, x1: i64, x2: i64, y1: i64, y2: i64) -> usize {
+        self.rects.push((x1, x2, y1, y2));
+        self.rects.len() - 1
+    }
+    fn solve(&self) -> Vec<usize> {
+        let mut pts = self.pts.clone();
+        pts.sort();
+        let (_, ys) = compress(&pts.iter().map(|p| p.1).collect::<Vec<_>>());
+        // (x, query, is x2): count points with px < x inside [y1, y2)
+        let mut ev = Vec::with_capacity(2 * self.rects.len());
+        for (i, &(x1, x2, _, _)) in self.rects.iter().enumerate() {
+            ev.push((x1, i, false));
+            ev.push((x2.max(x1), i, true));
+        }
+        ev.sort();
+        let mut fw = Fenwick::<usize>::new(ys.len());
+        // points left of x2, points left of x1
+        let mut res = vec![(0, 0); self.rects.len()];
+        let mut p = 0;
+        for (x, i, plus) in ev {
+            while p < pts.len() && pts[p].0 < x {
+                fw.add(ys.partition_point(|&y| y < pts[p].1), 1);
+                p += 1;
+            }
+            let (_, _, y1, y2) = self.rects[i];
+            let lo = ys.partition_point(|&y| y < y1);
+            let hi = ys.partition_point(|&y| y < y2).max(lo);
+            let c = fw.range_sum(lo, hi);
+            if plus {
+                res[i].0 = c;
+            } else {
+                res[i].1 = c;
+            }
+        }
+        res.into_iter().map(|(a, b)| a - b).collect()
+    }
+}
+
+// euler tour + sparse table: O(n log n) build, O(1) lca, for millions of queries.
+// tour lists a vertex every time the dfs enters or comes back to it (2n - 1 entries)
+#[allow(dead_code)]
+#[derive(Clone, Debug)]
+struct EulerLca {
+    first: Vec<usize>,
+    depth: Vec<usize>,
+    // sp[k][i] = shallowest vertex of tour[i..i + 2^k]
+    sp: Vec<Vec<usize>>,
+}
+#[allow(dead_code)]
+impl EulerLca {
+    fn new(g: &Graph, root: usize) -> Self {
+        let n = g.n;
+        let mut first = vec![usize::MAX; n];
+        let mut depth = vec![0; n];
+        let mut tour = Vec::with_capacity(2 * n);
+        let mut stack = vec![(root, 0)];
+        first[root] = 0;
+        tour.push(root);
+        while let Some(&mut (u, ref mut i)) = stack.last_mut() {
+            if let Some(&(v, _)) = g.adj[u].get(*i) {
+                *i += 1;
+                if first[v] == usize::MAX {
+                    depth[v] = depth[u] + 1;
+                    first[v] = tour.len();
+                    tour.push(v);
+                    stack.push((v, 0));
+                }
+            } else {
+                stack.pop();
+                if let Some(&(p, _)) = stack.last() {
+                    tour.push(p);
+                }
+            }
+        }
+        let mut sp = vec![tour];
+        let mut k = 1;
+        while (1 << k) <= sp[0].len() {
+            let prev = &sp[k - 1];
+            let row = (0..=sp[0].len() - (1 << k))
+                .map(|i| {
+                    let (a, b) = (prev[i], prev[i + (1 << (k - 1))]);
+                    if depth[a] <= depth[b] {
+                        a
+                    } else {
+                        b
+                    }
+                })
+                .collect();
+            sp.push(row);
+            k += 1;
+        }
+        Self { first, depth, sp }
+    }
+    fn lca(&self, u: usize, v: usize) -> usize {
+        let (l, r) = {
+            let (a, b) = (self.first[u], self.first[v]);
+            (a.min(b), a.max(b) + 1)
+        };
+        let k = highest_bit((r - l) as u64) as usize;
+        let (a, b) = (self.sp[k][l], self.sp[k][r - (1 << k)]);
+        if self.depth[a] <= self.depth[b] {
+            a
+        } else {
+            b
+        }
+    }
+    fn dist(&self, u: usize, v: usize) -> usize {
+        self.depth[u] + self.depth[v] - 2 * self.depth[self.lca(u, v)]
+    }
+}
+
+// min cost flow with successive shortest paths (spfa, so negative costs are fine
+// as long as there is no negative cycle). edge 2i is the i-th added edge, 2i + 1
+// its reverse
+#[allow(dead_code)]
+#[derive(Clone, Debug)]
+struct MinCostFlow {
+    n: usize,
+    adj: Vec<Vec<usize>>,
+    to: Vec<usize>,
+    cap: Vec<i64>,
+    cost: Vec<i64>,
+}
+#[allow(dead_code)]
+impl MinCostFlow {
+    fn new(n: usize) -> Self {
+        Self {
+            n,
+            adj: vec![Vec::new(); n],
+            to: Vec::new(),
+            cap: Vec::new(),
+            cost: Vec::new(),
+        }
+    }
+    // returns the edge id, flow on it afterwards is flow_on(id)
+    fn add_edge(&mut self, u: usize, v: usize, cap: i64, cost: i64) -> usize {
+        let id = self.to.len();
+        self.adj[u].push(id);
+        self.to.push(v);
+        self.cap.push(cap);
+        self.cost.push(cost);
+        self.adj[v].push(id + 1);
+        self.to.push(u);
+        self.cap.push(0);
+        self.cost.push(-cost);
+        id
+    }
+    fn flow_on(&self, id: usize) -> i64 {
+        self.cap[id ^ 1]
+    }
+    // pushes up to limit units from s to t, cheapest paths first. returns (flow, cost)
+    fn flow(&mut self, s: usize, t: usize, limit: i64) -> (i64, i64) {
+        let (mut flow, mut total) = (0, 0);
+        while flow < limit {
+            let mut dist = vec![i64::MAX; self.n];
+            let mut prev = vec![usize::MAX; self.n];
+            let mut inq = vec![false; self.n];
+            let mut q = std::collections::VecDeque::new();
+            dist[s] = 0;
+            q.push_back(s);
+            while let Some(u) = q.pop_front() {
+                inq[u] = false;
+                for &e in &self.adj[u] {
+                    let v = self.to[e];
+                    if self.cap[e] > 0 && dist[u] + self.cost[e] < dist[v] {
+                        dist[v] = dist[u] + self.cost[e];
+                        prev[v] = e;
+                        if !inq[v] {
+                            inq[v] = true;
+                            q.push_back(v);
+                        }
+                    }
+                }
+            }
+            if dist[t] == i64::MAX {
+                break;
+            }
+            let mut push = limit - flow;
+            let mut v = t;
+            while v != s {
+                push = push.min(self.cap[prev[v]]);
+                v = self.to[prev[v] ^ 1];
+            }
+            let mut v = t;
+            while v != s {
+                self.cap[prev[v]] -= push;
+                self.cap[prev[v] ^ 1] += push;
+                v = self.to[prev[v] ^ 1];
+            }
+            flow += push;
+            total += push * dist[t];
+        }
+        (flow, total)
+    }
+}
+
+// assignment with capacities: left i can be used up to lcap[i] times, right j up
+// to rcap[j] times, each add_pair(l, r, cost) may be used once
+#[allow(dead_code)]
+#[derive(Clone, Debug)]
+struct BMatching {
+    nl: usize,
+    mcf: MinCostFlow,
+    pairs: Vec<(usize, usize, usize)>,
+}
+#[allow(dead_code)]
+impl BMatching {
+    fn new(lcap: &[i64], rcap: &[i64]) -> Self {
+        let (nl, nr) = (lcap.len(), rcap.len());
+        // source = nl + nr, sink = nl + nr + 1
+        let mut mcf = MinCostFlow::new(nl + nr + 2);
+        for (i, &c) in lcap.iter().enumerate() {
+            mcf.add_edge(nl + nr, i, c, 0);
+        }
+        for (j, &c) in rcap.iter().enumerate() {
+            mcf.add_edge(nl + j, nl + nr + 1, c, 0);
+        }
+        Self {
+            nl,
+            mcf,
+            pairs: Vec::new(),
+        }
+    }
+    fn add_pair(&mut self, l: usize, r: usize, cost: i64) {
+        let id = self.mcf.add_edge(l, self.nl + r, 1, cost);
+        self.pairs.push((l, r, id));
+    }
+    // largest matching, cheapest among those: (size, cost, used (l, r) pairs).
+    // negate costs to maximize weight
+    fn solve(&mut self) -> (i64, i64, Vec<(usize, usize)>) {
+        let (s, t) = (self.mcf.n - 2, self.mcf.n - 1);
+        let (f, c) = self.mcf.flow(s, t, i64::MAX);
+        let used = self
+            .pairs
+            .iter()
+            .filter(|p| self.mcf.flow_on(p.2) > 0)
+            .map(|p| (p.0, p.1))
+            .collect();
+        (f, c, used)
+    }
+}
+
+// dsu on tree (sack): answer(state, v) is called when state holds exactly the
+// vertices of v's subtree. each vertex is added O(log n) times in total.
+// state is passed around like in two_pointers so the closures don't fight over it
+#[allow(dead_code)]
+fn dsu_on_tree<S>(
+    g: &Graph,
+    root: usize,
+    state: &mut S,
+    mut add: impl FnMut(&mut S, usize),
+    mut remove: impl FnMut(&mut S, usize),
+    mut answer: impl FnMut(&mut S, usize),
+) {
+    let d = g.dfs_full(root);
+    let size = |v: usize| d.tout[v] - d.tin[v];
+    // light children first, heavy child last
+    let children: Vec<Vec<usize>> = (0..g.n)
+        .map(|u| {
+            let mut c: Vec<usize> = g.adj[u]
+                .iter()
+                .map(|e| e.0)
+                .filter(|&v| d.parent[v] == u && v != root)
+                .collect();
+            // parallel edges would list a child twice and merge its subtree twice
+            c.sort_unstable();
+            c.dedup();
+            if let Some(h) = (0..c.len()).max_by_key(|&i| size(c[i])) {
+                let last = c.len() - 1;
+                c.swap(h, last);
+            }
+            c
+        })
+        .collect();
+    // (vertex, keep its vertices in state afterwards, next child)
+    let mut stack = vec![(root, true, 0)];
+    while let Some(&mut (v, keep, ref mut i)) = stack.last_mut() {
+        if *i < children[v].len() {
+            let c = children[v][*i];
+            *i += 1;
+            let heavy = *i == children[v].len();
+            stack.push((c, heavy, 0));
+            continue;
+        }
+        stack.pop();
+        let light = &children[v][..children[v].len().saturating_sub(1)];
+        for &c in light {
+            for &u in &d.order[d.tin[c]..d.tout[c]] {
+                add(state, u);
+            }
+        }
+        add(state, v);
+        answer(state, v);
+        if !keep {
+            for &u in &d.order[d.tin[v]..d.tout[v]] {
+                remove(state, u);
+            }
+        }
+    }
+}
+
+// tarjan's offline lca: one dfs + union find, O((n + q) α) time and O(n + q) memory
+#[allow(dead_code)]
+fn offline_lca(g: &Graph, root: usize, queries: &[(usize, usize)]) -> Vec<usize> {
+    let n = g.n;
+    let mut at: Vec<Vec<(usize, usize)>> = vec![Vec::new(); n];
+    for (i, &(u, v)) in queries.iter().enumerate() {
+        at[u].push((v, i));
+        at[v].push((u, i));
+    }
+    let mut dsu: Vec<usize> = (0..n).collect();
+    fn find(dsu: &mut [usize], x: usize) -> usize {
+        let mut r = x;
+        while dsu[r] != r {
+            r = dsu[r];
+        }
+        let mut x = x;
+        while dsu[x] != r {
+            let next = dsu[x];
+            dsu[x] = r;
+            x = next;
+        }
+        r
+    }
+    // anc[find(v)] = the deepest vertex on the current path whose set contains v
+    let mut anc: Vec<usize> = (0..n).collect();
+    let mut seen = vec![false; n];
+    let mut done = vec![false; n];
+    let mut res = vec![usize::MAX; queries.len()];
+    let mut stack = vec![(root, 0)];
+    seen[root] = true;
+    while let Some(&mut (u, ref mut i)) = stack.last_mut() {
+        if let Some(&(v, _)) = g.adj[u].get(*i) {
+            *i += 1;
+            if !seen[v] {
+                seen[v] = true;
+                stack.push((v, 0));
+            }
+            continue;
+        }
+        stack.pop();
+        done[u] = true;
+        for &(v, qi) in &at[u] {
+            if done[v] {
+                res[qi] = anc[find(&mut dsu, v)];
+            }
+        }
+        if let Some(&(p, _)) = stack.last() {
+            let (a, b) = (find(&mut dsu, u), find(&mut dsu, p));
+            dsu[a] = b;
+            anc[b] = p;
+        }
+    }
+    res
+}
+
+// split [0, n) into m non-empty segments [j, i) minimizing the sum of cost(j, i).
+// needs the best split point to be monotone (true when cost satisfies the quadrangle
+// inequality), then it's O(m n log n) instead of O(m n^2). i64::MAX if m > n
+#[allow(dead_code)]
+fn dnc_dp_optimize(cost: impl Fn(usize, usize) -> i64, n: usize, m: usize) -> i64 {
+    const INF: i64 = i64::MAX;
+    // dp[i] = best for the prefix [0, i) with the current number of segments
+    let mut dp: Vec<i64> = (0..=n).map(|i| if i == 0 { 0 } else { INF }).collect();
+    for _ in 0..m {
+        let mut nd = vec![INF; n + 1];
+        // (lo, hi, opt_lo, opt_hi): fill nd[lo..hi] knowing opt is in [opt_lo, opt_hi]
+        let mut st = vec![(1, n + 1, 0, n)];
+        while let Some((lo, hi, olo, ohi)) = st.pop() {
+            if lo >= hi {
+                continue;
+            }
+            let mid = (lo + hi) / 2;
+            let mut best = (INF, olo);
+            for (j, &d) in dp.iter().enumerate().take(ohi.min(mid - 1) + 1).skip(olo) {
+                if d < INF {
+                    let v = d + cost(j, mid);
+                    if v < best.0 {
+                        best = (v, j);
+                    }
+                }
+            }
+            nd[mid] = best.0;
+            st.push((lo, mid, olo, best.1));
+            st.push((mid + 1, hi, best.1, ohi));
+        }
+        dp = nd;
+    }
+    dp[n]
+}
+
+// knuth: dp[l][r] = cost(l, r) + min over l < k < r of dp[l][k] + dp[k][r], with
+// dp[i][i + 1] = 0 (merging adjacent piles, optimal bst ..). needs
+// opt[l][r - 1] <= opt[l][r] <= opt[l + 1][r], O(n^2). returns dp[0][n]
+#[allow(dead_code)]
+fn knuth_optimize(n: usize, cost: impl Fn(usize, usize) -> i64) -> i64 {
+    if n <= 1 {
+        return 0;
+    }
+    let mut dp = vec![vec![0i64; n + 1]; n + 1];
+    let mut opt = vec![vec![0usize; n + 1]; n + 1];
+    for i in 0..n {
+        opt[i][i + 1] = i + 1;
+    }
+    for len in 2..=n {
+        for l in 0..=n - len {
+            let r = l + len;
+            let (lo, hi) = (opt[l][r - 1].max(l + 1), opt[l + 1][r].min(r - 1));
+            let mut best = (i64::MAX, lo);
+            for (k, &left) in dp[l].iter().enumerate().take(hi + 1).skip(lo) {
+                let v = left + dp[k][r];
+                if v < best.0 {
+                    best = (v, k);
+                }
+            }
+            dp[l][r] = best.0 + cost(l, r);
+            opt[l][r] = best.1;
+        }
+    }
+    dp[0][n]
+}
+
+// aliens trick / lagrangian relaxation for "min cost using exactly k items" when the
+// answer f(k) is convex in k. solve(lambda) must return (min of cost + lambda * count,
+// count) and break ties towards the SMALLEST count. lambda is searched in [lo, hi],
+// pick it so that count(lo) >= k and count(hi) <= k (roughly +-max marginal cost)
+#[allow(dead_code)]
+fn aliens_trick(k: i64, (lo, hi): (i64, i64), solve: impl Fn(i64) -> (i64, i64)) -> i64 {
+    // smallest lambda whose optimal count is <= k
+    let lambda = binary_search_answer(lo, hi, |l| solve(l).1 <= k);
+    assert!(lambda <= hi, "aliens_trick: count(hi) is still > k");
+    solve(lambda).0 - lambda * k
+}
+
+// slope trick: a convex piecewise linear f(x) kept as its minimum plus the breakpoints
+// left of the minimum (max heap) and right of it (min heap), each with a lazy shift.
+// starts as f(x) = 0
+#[allow(dead_code)]
+#[derive(Clone, Debug, Default)]
+struct SlopeTrick {
+    min_f: i64,
+    l: std::collections::BinaryHeap<i64>,
+    r: std::collections::BinaryHeap<std::cmp::Reverse<i64>>,
+    add_l: i64,
+    add_r: i64,
+}
+#[allow(dead_code)]
+impl SlopeTrick {
+    fn new() -> Self {
+        Self::default()
+    }
+    fn top_l(&self) -> i64 {
+        self.l.peek().map_or(i64::MIN, |&x| x + self.add_l)
+    }
+    fn top_r(&self) -> i64 {
+        self.r.peek().map_or(i64::MAX, |x| x.0 + self.add_r)
+    }
+    fn push_l(&mut self, a: i64) {
+        self.l.push(a - self.add_l);
+    }
+    fn push_r(&mut self, a: i64) {
+        self.r.push(std::cmp::Reverse(a - self.add_r));
+    }
+    fn pop_l(&mut self) -> i64 {
+        self.l.pop().unwrap() + self.add_l
+    }
+    fn pop_r(&mut self) -> i64 {
+        self.r.pop().unwrap().0 + self.add_r
+    }
+    fn min(&self) -> i64 {
+        self.min_f
+    }
+    // the x range where f is minimal (i64::MIN / MAX when unbounded)
+    fn argmin(&self) -> (i64, i64) {
+        (self.top_l(), self.top_r())
+    }
+    fn add_const(&mut self, c: i64) {
+        self.min_f += c;
+    }
+    // f += max(0, x - a)
+    fn add_x_minus_a(&mut self, a: i64) {
+        let t = self.top_l();
+        if t > a {
+            self.min_f += t - a;
+        }
+        self.push_l(a);
+        let x = self.pop_l();
+        self.push_r(x);
+    }
+    // f += max(0, a - x)
+    fn add_a_minus_x(&mut self, a: i64) {
+        let t = self.top_r();
+        if t < a {
+            self.min_f += a - t;
+        }
+        self.push_r(a);
+        let x = self.pop_r();
+        self.push_l(x);
+    }
+    // f += |x - a|
+    fn add_abs(&mut self, a: i64) {
+        self.add_x_minus_a(a);
+        self.add_a_minus_x(a);
+    }
+    // f(x) = min over y <= x of f(y)
+    fn prefix_min(&mut self) {
+        self.r.clear();
+    }
+    // f(x) = min over y >= x of f(y)
+    fn suffix_min(&mut self) {
+        self.l.clear();
+    }
+    // f(x) = min over x - b <= y <= x - a of f(y), needs a <= b.
+    // shift(a, a) just moves the graph right by a
+    fn shift(&mut self, a: i64, b: i64) {
+        assert!(a <= b, "SlopeTrick::shift needs a <= b");
+        self.add_l += a;
+        self.add_r += b;
+    }
+}
+
+// zobrist hashing: every distinct value gets a random u64. two multisets are equal
+// (whp) iff the sums of their values' hashes are equal; summed as i128 so nothing
+// wraps and fenwick updates can subtract. xor works for sets (pairs cancel out)
+#[allow(dead_code)]
+struct ZobristHasher<T: std::hash::Hash + Eq> {
+    rng: Rng,
+    ids: HashMap<T, u64>,
+}
+#[allow(dead_code)]
+impl<T: std::hash::Hash + Eq + Clone> ZobristHasher<T> {
+    fn new() -> Self {
+        Self {
+            rng: Rng::new(),
+            ids: HashMap::new(),
+        }
+    }
+    fn get(&mut self, x: &T) -> u64 {
+        if let Some(&h) = self.ids.get(x) {
+            return h;
+        }
+        let h = self.rng.next_u64();
+        self.ids.insert(x.clone(), h);
+        h
+    }
+    fn sum_hash(&mut self, a: &[T]) -> i128 {
+        a.iter().map(|x| self.get(x) as i128).sum()
+    }
+    fn xor_hash(&mut self, a: &[T]) -> u64 {
+        a.iter().fold(0, |h, x| h ^ self.get(x))
+    }
+    // ps.sum(l..r) == multiset hash of a[l..r]
+    fn prefix(&mut self, a: &[T]) -> PrefixSum<i128> {
+        let v: Vec<i128> = a.iter().map(|x| self.get(x) as i128).collect();
+        PrefixSum::new(&v)
+    }
+    // same with point updates, a[i] = y is fw.add(i, h(y) - h(old a[i]))
+    fn fenwick(&mut self, a: &[T]) -> Fenwick<i128> {
+        let mut fw = Fenwick::new(a.len());
+        for (i, x) in a.iter().enumerate() {
+            fw.add(i, self.get(x) as i128);
+        }
+        fw
+    }
+}
+
+#[allow(dead_code)]
+impl Graph {
+    // i64::MAX = unreachable. weights must be >= 0
+    fn dijkstra(&self, src: usize) -> Vec<i64> {
+        let mut dist = vec![i64::MAX; self.n];
+        let mut pq = std::collections::BinaryHeap::new();
+        dist[src] = 0;
+        pq.push(std::cmp::Reverse((0, src)));
+        while let Some(std::cmp::Reverse((d, u))) = pq.pop() {
+            if d > dist[u] {
+                continue;
+            }
+            for &(v, w) in &self.adj[u] {
+                if d + w < dist[v] {
+                    dist[v] = d + w;
+                    pq.push(std::cmp::Reverse((dist[v], v)));
+                }
+            }
+        }
+        dist
+    }
+    // all pairs shortest paths with negative edges: bellman-ford potentials once,
+    // then every source is a plain dijkstra. None if there is a negative cycle
+    fn johnson(&self) -> Option<Johnson> {
+        // h = distances from a virtual source joined to every vertex with weight 0
+        let mut h = vec![0i64; self.n];
+        for round in 0..=self.n {
+            let mut changed = false;
+            for u in 0..self.n {
+                for &(v, w) in &self.adj[u] {
+                    if h[u] + w < h[v] {
+                        h[v] = h[u] + w;
+                        changed = true;
+                    }
+                }
+            }
+            if !changed {
+                break;
+            }
+            if round == self.n {
+                return None;
+            }
+        }
+        let mut g = Graph::new(self.n);
+        for u in 0..self.n {
+            for &(v, w) in &self.adj[u] {
+                g.add_edge(u, v, w + h[u] - h[v]);
+            }
+        }
+        Some(Johnson { h, g })
+    }
+}
+// reweighted graph (all weights >= 0) + potentials, O(n + m) memory.
+// dist_from(s) costs one dijkstra, all_pairs() builds the full n x n table
+#[allow(dead_code)]
+#[derive(Clone, Debug)]
+struct Johnson {
+    h: Vec<i64>,
+    g: Graph,
+}
+#[allow(dead_code)]
+impl Johnson {
+    fn dist_from(&self, s: usize) -> Vec<i64> {
+        let mut d = self.g.dijkstra(s);
+        for (v, x) in d.iter_mut().enumerate() {
+            if *x != i64::MAX {
+                *x = *x - self.h[s] + self.h[v];
+            }
+        }
+        d
+    }
+    fn all_pairs(&self) -> Vec<Vec<i64>> {
+        (0..self.g.n).map(|s| self.dist_from(s)).collect()
+    }
+}
+
+#[allow(dead_code)]
+impl Graph {
+    // maximum matching in a general (non bipartite) undirected graph, edmonds' blossom,
+    // O(n^3). returns (size, mate) with mate[v] = usize::MAX when v is unmatched
+    fn general_matching(&self) -> (usize, Vec<usize>) {
+        const NONE: usize = usize::MAX;
+        let n = self.n;
+        let mut mate = vec![NONE; n];
+        let mut size = 0;
+        for root in 0..n {
+            if mate[root] != NONE {
+                continue;
+            }
+            // bfs over alternating paths, blossoms contracted through base[]
+            let mut par = vec![NONE; n];
+            let mut base: Vec<usize> = (0..n).collect();
+            let mut used = vec![false; n];
+            let mut q = std::collections::VecDeque::new();
+            used[root] = true;
+            q.push_back(root);
+            let mut end = NONE;
+            'bfs: while let Some(v) = q.pop_front() {
+                for &(to, _) in &self.adj[v] {
+                    if base[v] == base[to] || mate[v] == to {
+                        continue;
+                    }
+                    if to == root || (mate[to] != NONE && par[mate[to]] != NONE) {
+                        // odd cycle: contract the blossom into its base
+                        let cur = {
+                            let mut seen = vec![false; n];
+                            let mut a = v;
+                            loop {
+                                a = base[a];
+                                seen[a] = true;
+                                if mate[a] == NONE {
+                                    break;
+                                }
+                                a = par[mate[a]];
+                            }
+                            let mut b = to;
+                            loop {
+                                b = base[b];
+                                if seen[b] {
+                                    break b;
+                                }
+                                b = par[mate[b]];
+                            }
+                        };
+                        let mut blossom = vec![false; n];
+                        for (start, mut child) in [(v, to), (to, v)] {
+                            let mut x = start;
+                            while base[x] != cur {
+                                blossom[base[x]] = true;
+                                blossom[base[mate[x]]] = true;
+                                par[x] = child;
+                                child = mate[x];
+                                x = par[mate[x]];
+                            }
+                        }
+                        for i in 0..n {
+                            if blossom[base[i]] {
+                                base[i] = cur;
+                                if !used[i] {
+                                    used[i] = true;
+                                    q.push_back(i);
+                                }
+                            }
+                        }
+                    } else if par[to] == NONE {
+                        par[to] = v;
+                        if mate[to] == NONE {
+                            end = to;
+                            break 'bfs;
+                        }
+                        used[mate[to]] = true;
+                        q.push_back(mate[to]);
+                    }
+                }
+            }
+            // flip the augmenting path root .. end
+            let mut v = end;
+            while v != NONE {
+                let pv = par[v];
+                let ppv = mate[pv];
+                mate[v] = pv;
+                mate[pv] = v;
+                v = ppv;
+            }
+            if end != NONE {
+                size += 1;
+            }
+        }
+        (size, mate)
+    }
+}
+
+// determinant mod a prime p (< 2^32) by gaussian elimination, O(n^3). entries
+// must already be reduced mod p
+#[allow(dead_code)]
+fn det_mod(mut a: Matrix, p: u64) -> u64 {
+    let n = a.len();
+    let mut det = 1;
+    for c in 0..n {
+        let Some(piv) = (c..n).find(|&r| a[r][c] != 0) else {
+            return 0;
+        };
+        if piv != c {
+            a.swap(piv, c);
+            det = (p - det) % p;
+        }
+        det = det * a[c][c] % p;
+        let inv = modexp(a[c][c], p - 2, p);
+        for r in c + 1..n {
+            let f = a[r][c] * inv % p;
+            if f == 0 {
+                continue;
+            }
+            let (top, bottom) = a.split_at_mut(r);
+            for (x, &y) in bottom[0][c..].iter_mut().zip(&top[c][c..]) {
+                *x = (*x + p - f * y % p) % p;
+            }
+        }
+    }
+    det
+}
+// kirchhoff: number of spanning trees of an undirected multigraph mod a prime,
+// determinant of the laplacian without its first row / column. self loops ignored
+#[allow(dead_code)]
+fn count_spanning_trees(g: &Graph, p: u64) -> u64 {
+    if g.n == 0 {
+        return 0;
+    }
+    let mut lap = vec![vec![0u64; g.n]; g.n];
+    for (u, row) in lap.iter_mut().enumerate() {
+        for &(v, _) in &g.adj[u] {
+            if u != v {
+                row[u] = (row[u] + 1) % p;
+                row[v] = (row[v] + p - 1) % p;
+            }
+        }
+    }
+    let minor = lap[1..].iter().map(|row| row[1..].to_vec()).collect();
+    det_mod(minor, p)
+}
+
+#[allow(dead_code)]
+impl Graph {
+    // shortest paths with negative weights, O(n m). dist is i64::MAX when unreachable
+    // and i64::MIN when a negative cycle reachable from src can make it arbitrarily
+    // small. the second value is one such cycle (vertices in edge order), if any
+    fn bellman_ford(&self, src: usize) -> (Vec<i64>, Option<Vec<usize>>) {
+        let n = self.n;
+        let mut dist = vec![i64::MAX; n];
+        let mut pred = vec![usize::MAX; n];
+        dist[src] = 0;
+        let mut last = usize::MAX;
+        for _ in 0..n {
+            last = usize::MAX;
+            for u in 0..n {
+                if dist[u] == i64::MAX {
+                    continue;
+                }
+                for &(v, w) in &self.adj[u] {
+                    if dist[u] + w < dist[v] {
+                        dist[v] = dist[u] + w;
+                        pred[v] = u;
+                        last = v;
+                    }
+                }
+            }
+            if last == usize::MAX {
+                return (dist, None);
+            }
+        }
+        // still relaxing after n rounds: walking pred n times from last lands on a cycle
+        let mut x = last;
+        for _ in 0..n {
+            x = pred[x];
+        }
+        let mut cycle = vec![x];
+        let mut y = pred[x];
+        while y != x {
+            cycle.push(y);
+            y = pred[y];
+        }
+        cycle.reverse();
+        // everything reachable from a vertex that can still relax is -inf
+        let mut stack = Vec::new();
+        for u in 0..n {
+            if dist[u] != i64::MAX && self.adj[u].iter().any(|&(v, w)| dist[u] + w < dist[v]) {
+                stack.push(u);
+            }
+        }
+        while let Some(u) = stack.pop() {
+            if dist[u] == i64::MIN {
+                continue;
+            }
+            dist[u] = i64::MIN;
+            for &(v, _) in &self.adj[u] {
+                if dist[v] != i64::MIN {
+                    stack.push(v);
+                }
+            }
+        }
+        (dist, Some(cycle))
+    }
+}
+
+// robbins: a connected undirected graph without bridges can be oriented so it becomes
+// strongly connected. dfs tree edges point down, back edges point up. returns every
+// edge once as (from, to) (self loops left out), None if disconnected or a bridge exists
+#[allow(dead_code)]
+fn orient_edges_strongly(g: &Graph) -> Option<Vec<(usize, usize)>> {
+    let n = g.n;
+    if n == 0 {
+        return Some(Vec::new());
+    }
+    let mut tin = vec![usize::MAX; n];
+    let mut low = vec![0; n];
+    let mut res = Vec::new();
+    // (vertex, parent, next adj index, skipped the edge to the parent already)
+    let mut stack = vec![(0, usize::MAX, 0, false)];
+    tin[0] = 0;
+    let mut timer = 1;
+    while let Some(&mut (u, p, ref mut i, ref mut skipped)) = stack.last_mut() {
+        if let Some(&(v, _)) = g.adj[u].get(*i) {
+            *i += 1;
+            if v == p && !*skipped {
+                // only the first copy, a parallel edge to the parent is a real back edge
+                *skipped = true;
+            } else if tin[v] == usize::MAX {
+                tin[v] = timer;
+                low[v] = timer;
+                timer += 1;
+                res.push((u, v));
+                stack.push((v, u, 0, false));
+            } else if tin[v] < tin[u] {
+                res.push((u, v));
+                low[u] = low[u].min(tin[v]);
+            }
+        } else {
+            stack.pop();
+            if p != usize::MAX {
+                if low[u] > tin[p] {
+                    return None;
+                }
+                low[p] = low[p].min(low[u]);
+            }
+        }
+    }
+    if timer < n {
+        return None;
+    }
+    Some(res)
+}
+
+#[allow(dead_code)]
+impl Graph {
+    // kahn's algorithm, None if the graph has a cycle
+    fn topological_sort(&self) -> Option<Vec<usize>> {
+        let mut indeg = vec![0; self.n];
+        for u in 0..self.n {
+            for &(v, _) in &self.adj[u] {
+                indeg[v] += 1;
+            }
+        }
+        let mut order: Vec<usize> = (0..self.n).filter(|&v| indeg[v] == 0).collect();
+        let mut head = 0;
+        while head < order.len() {
+            let u = order[head];
+            head += 1;
+            for &(v, _) in &self.adj[u] {
+                indeg[v] -= 1;
+                if indeg[v] == 0 {
+                    order.push(v);
+                }
+            }
+        }
+        (order.len() == self.n).then_some(order)
+    }
+    // dp over a dag: relax(&mut dp, u, v, w) runs for every edge u -> v, with u taken
+    // in topological order so dp[u] is final by then. None if there is a cycle
+    fn dag_dp<T>(
+        &self,
+        init: Vec<T>,
+        mut relax: impl FnMut(&mut Vec<T>, usize, usize, i64),
+    ) -> Option<Vec<T>> {
+        let order = self.topological_sort()?;
+        let mut dp = init;
+        for u in order {
+            for &(v, w) in &self.adj[u] {
+                relax(&mut dp, u, v, w);
+            }
+        }
+        Some(dp)
+    }
+    // heaviest path anywhere in the dag (edge count if unweighted): (length, vertices).
+    // None if there is a cycle
+    fn dag_longest_path(&self) -> Option<(i64, Vec<usize>)> {
+        if self.n == 0 {
+            return Some((0, Vec::new()));
+        }
+        // (best path length ending at v, previous vertex), a path may also start at v
+        let dp = self.dag_dp(vec![(0i64, usize::MAX); self.n], |dp, u, v, w| {
+            if dp[u].0 + w > dp[v].0 {
+                dp[v] = (dp[u].0 + w, u);
+            }
+        })?;
+        let mut end = (0..self.n).max_by_key(|&v| dp[v].0).unwrap();
+        let len = dp[end].0;
+        let mut path = vec![end];
+        while dp[end].1 != usize::MAX {
+            end = dp[end].1;
+            path.push(end);
+        }
+        path.reverse();
+        Some((len, path))
+    }
+}
+
+// dijkstra over an implicit graph of states 0..num_states: next(s) yields
+// (next_state, cost >= 0). encode the state yourself, e.g. cell * (1 << k) + keys.
+// every start state gets distance 0, i64::MAX = unreachable
+#[allow(dead_code)]
+fn dijkstra_states<I: IntoIterator<Item = (usize, i64)>>(
+    num_states: usize,
+    starts: &[usize],
+    mut next: impl FnMut(usize) -> I,
+) -> Vec<i64> {
+    let mut dist = vec![i64::MAX; num_states];
+    let mut pq = std::collections::BinaryHeap::new();
+    for &s in starts {
+        dist[s] = 0;
+        pq.push(std::cmp::Reverse((0, s)));
+    }
+    while let Some(std::cmp::Reverse((d, u))) = pq.pop() {
+        if d > dist[u] {
+            continue;
+        }
+        for (v, w) in next(u) {
+            if d + w < dist[v] {
+                dist[v] = d + w;
+                pq.push(std::cmp::Reverse((dist[v], v)));
+            }
+        }
+    }
+    dist
+}
+
+// kuhn's matching with bitset rows for dense bipartite graphs (n ~ 5000):
+// adj[l] has bit r set if l - r is an edge. a dfs step scans 64 right vertices per
+// word, O(n^3 / 64) overall. returns (size, mate of every right vertex or usize::MAX)
+#[allow(dead_code)]
+fn bitset_kuhn(adj: &[Bitset], nr: usize) -> (usize, Vec<usize>) {
+    fn go(u: usize, adj: &[Bitset], unvis: &mut Bitset, mate: &mut [usize]) -> bool {
+        for i in 0..unvis.w.len() {
+            let mut x = adj[u].w[i] & unvis.w[i];
+            while x != 0 {
+                let v = i * 64 + x.trailing_zeros() as usize;
+                unvis.reset(v);
+                if mate[v] == usize::MAX || go(mate[v], adj, unvis, mate) {
+                    mate[v] = u;
+                    return true;
+                }
+                // the recursion may have visited more of this word
+                x &= x - 1;
+                x &= unvis.w[i];
+            }
+        }
+        false
+    }
+    let mut mate = vec![usize::MAX; nr];
+    let all = || {
+        let mut b = Bitset::new(nr);
+        b.w.iter_mut().for_each(|x| *x = !0);
+        // keep the bits past nr clear
+        if !nr.is_multiple_of(64) {
+            let last = b.w.len() - 1;
+            b.w[last] = (1 << (nr % 64)) - 1;
+        }
+        b
+    };
+    let mut unvis = all();
+    let mut size = 0;
+    for u in 0..adj.len() {
+        if go(u, adj, &mut unvis, &mut mate) {
+            size += 1;
+            unvis = all();
+        }
+    }
+    (size, mate)
+}
+
+// hopcroft-karp, O(E sqrt V): each phase bfs-layers the graph from the free left
+// vertices, then augments along vertex-disjoint shortest paths with an iterative dfs
+#[allow(dead_code)]
+#[derive(Clone, Debug)]
+struct BipartiteMatching {
+    nl: usize,
+    nr: usize,
+    adj: Vec<Vec<usize>>,
+}
+#[allow(dead_code)]
+impl BipartiteMatching {
+    fn new(nl: usize, nr: usize) -> Self {
+        Self {
+            nl,
+            nr,
+            adj: vec![Vec::new(); nl],
+        }
+    }
+    fn add_edge(&mut self, l: usize, r: usize) {
+        self.adj[l].push(r);
+    }
+    // (size, mate of every left vertex, mate of every right vertex), usize::MAX if free
+    fn max_matching(&self) -> (usize, Vec<usize>, Vec<usize>) {
+        let mut ml = vec![usize::MAX; self.nl];
+        let mut mr = vec![usize::MAX; self.nr];
+        let mut size = 0;
+        loop {
+            let mut dist = vec![usize::MAX; self.nl];
+            let mut q = std::collections::VecDeque::new();
+            for u in 0..self.nl {
+                if ml[u] == usize::MAX {
+                    dist[u] = 0;
+                    q.push_back(u);
+                }
+            }
+            let mut found = false;
+            while let Some(u) = q.pop_front() {
+                for &v in &self.adj[u] {
+                    let w = mr[v];
+                    if w == usize::MAX {
+                        found = true;
+                    } else if dist[w] == usize::MAX {
+                        dist[w] = dist[u] + 1;
+                        q.push_back(w);
+                    }
+                }
+            }
+            if !found {
+                break;
+            }
+            let mut it = vec![0; self.nl];
+            for s in 0..self.nl {
+                if ml[s] != usize::MAX {
+                    continue;
+                }
+                let mut stack = vec![s];
+                while let Some(&u) = stack.last() {
+                    if it[u] == self.adj[u].len() {
+                        // dead end for the rest of the phase
+                        dist[u] = usize::MAX;
+                        stack.pop();
+                        continue;
+                    }
+                    let w = mr[self.adj[u][it[u]]];
+                    if w == usize::MAX {
+                        // free right vertex: flip every edge on the stack path
+                        for &x in &stack {
+                            let v = self.adj[x][it[x]];
+                            ml[x] = v;
+                            mr[v] = x;
+                        }
+                        size += 1;
+                        break;
+                    }
+                    if dist[w] == dist[u] + 1 {
+                        stack.push(w);
+                    } else {
+                        it[u] += 1;
+                    }
+                }
+            }
+        }
+        (size, ml, mr)
+    }
+}
+
+#[allow(dead_code)]
+impl Graph {
+    // transitive closure of a dag as bitsets: reach[u] has v set iff u reaches v
+    // (u itself included). O(n m / 64) time, n^2 / 8 bytes. None if there is a cycle
+    fn dag_reachability(&self) -> Option<Vec<Bitset>> {
+        let mut rg = Graph::new(self.n);
+        for u in 0..self.n {
+            for &(v, w) in &self.adj[u] {
+                rg.add_edge(v, u, w);
+            }
+        }
+        let init = (0..self.n)
+            .map(|v| {
+                let mut b = Bitset::new(self.n);
+                b.set(v);
+                b
+            })
+            .collect();
+        // reversed topological order: everything v reaches is final when u takes it
+        rg.dag_dp(init, |reach: &mut Vec<Bitset>, v, u, _| {
+            let r = reach[v].clone();
+            reach[u].or_with(&r);
+        })
+    }
+}
+
+#[allow(dead_code)]
+impl Graph {
+    // drops self loops and parallel edges (keeping the lightest copy) so algorithms
+    // that assume a simple graph behave. returns how many (loops, duplicates) were
+    // removed, counted as adjacency entries (an undirected edge counts twice).
+    // adjacency lists end up sorted by neighbor
+    fn dedup_edges(&mut self) -> (usize, usize) {
+        let (mut loops, mut dups) = (0, 0);
+        for u in 0..self.n {
+            let adj = &mut self.adj[u];
+            let before = adj.len();
+            adj.retain(|&(v, _)| v != u);
+            loops += before - adj.len();
+            adj.sort_unstable();
+            let before = adj.len();
+            adj.dedup_by_key(|e| e.0);
+            dups += before - adj.len();
+        }
+        (loops, dups)
+    }
+}
+
+// connected components of the complement of g (u - v joined iff NOT an edge of g),
+// O(n + m) without building the dense complement. each step splits the unvisited
+// vertices into g-neighbors of u (stay) and the rest (reached through the complement)
+#[allow(dead_code)]
+fn complement_bfs(g: &Graph) -> Vec<Vec<usize>> {
+    let mut unvis: Vec<usize> = (0..g.n).collect();
+    let mut mark = vec![usize::MAX; g.n];
+    let mut comps = Vec::new();
+    while let Some(s) = unvis.pop() {
+        let mut comp = vec![s];
+        let mut head = 0;
+        while head < comp.len() {
+            let u = comp[head];
+            head += 1;
+            for &(v, _) in &g.adj[u] {
+                mark[v] = u;
+            }
+            let (stay, go): (Vec<usize>, Vec<usize>) = unvis.iter().partition(|&&v| mark[v] == u);
+            unvis = stay;
+            comp.extend(go);
+        }
+        comps.push(comp);
+    }
+    comps
+}
+
+#[allow(dead_code)]
+impl Graph {
+    // strongly connected components (kosaraju, iterative): (count, comp[v]).
+    // components are numbered in topological order, edges go from lower to higher ids
+    fn scc(&self) -> (usize, Vec<usize>) {
+        let n = self.n;
+        let mut seen = vec![false; n];
+        let mut finish = Vec::with_capacity(n);
+        for s in 0..n {
+            if seen[s] {
+                continue;
+            }
+            seen[s] = true;
+            let mut stack = vec![(s, 0)];
+            while let Some(&mut (u, ref mut i)) = stack.last_mut() {
+                if let Some(&(v, _)) = self.adj[u].get(*i) {
+                    *i += 1;
+                    if !seen[v] {
+                        seen[v] = true;
+                        stack.push((v, 0));
+                    }
+                } else {
+                    finish.push(u);
+                    stack.pop();
+                }
+            }
+        }
+        let mut radj = vec![Vec::new(); n];
+        for u in 0..n {
+            for &(v, _) in &self.adj[u] {
+                radj[v].push(u);
+            }
+        }
+        let mut comp = vec![usize::MAX; n];
+        let mut count = 0;
+        for &s in finish.iter().rev() {
+            if comp[s] != usize::MAX {
+                continue;
+            }
+            comp[s] = count;
+            let mut stack = vec![s];
+            while let Some(u) = stack.pop() {
+                for &v in &radj[u] {
+                    if comp[v] == usize::MAX {
+                        comp[v] = count;
+                        stack.push(v);
+                    }
+                }
+            }
+            count += 1;
+        }
+        (count, comp)
+    }
+    // the dag of strongly connected components, without self loops and parallel edges
+    // (lightest kept), plus comp[v]. vertex ids are already a topological order
+    fn condensation(&self) -> (Graph, Vec<usize>) {
+        let (count, comp) = self.scc();
+        let mut dag = Graph::new(count);
+        for u in 0..self.n {
+            for &(v, w) in &self.adj[u] {
+                dag.add_edge(comp[u], comp[v], w);
+            }
+        }
+        dag.dedup_edges();
+        (dag, comp)
+    }
+}
+
+#[allow(dead_code)]
+impl Graph {
+    // dfs over every component of an undirected graph: (tin, low, parent), parent is
+    // usize::MAX for roots. only the first copy of the edge to the parent is skipped,
+    // so parallel edges count as cycles
+    fn lowlink(&self) -> (Vec<usize>, Vec<usize>, Vec<usize>) {
+        let n = self.n;
+        let mut tin = vec![usize::MAX; n];
+        let mut low = vec![0; n];
+        let mut parent = vec![usize::MAX; n];
+        let mut timer = 0;
+        for s in 0..n {
+            if tin[s] != usize::MAX {
+                continue;
+            }
+            tin[s] = timer;
+            low[s] = timer;
+            timer += 1;
+            // (vertex, next adj index, skipped the edge to the parent already)
+            let mut stack = vec![(s, 0, false)];
+            while let Some(&mut (u, ref mut i, ref mut skipped)) = stack.last_mut() {
+                if let Some(&(v, _)) = self.adj[u].get(*i) {
+                    *i += 1;
+                    if v == parent[u] && !*skipped {
+                        *skipped = true;
+                    } else if tin[v] == usize::MAX {
+                        tin[v] = timer;
+                        low[v] = timer;
+                        timer += 1;
+                        parent[v] = u;
+                        stack.push((v, 0, false));
+                    } else {
+                        low[u] = low[u].min(tin[v]);
+                    }
+                } else {
+                    stack.pop();
+                    if parent[u] != usize::MAX {
+                        let p = parent[u];
+                        low[p] = low[p].min(low[u]);
+                    }
+                }
+            }
+        }
+        (tin, low, parent)
+    }
+    // edges whose removal disconnects their component, as (parent, child) in the dfs
+    fn bridges(&self) -> Vec<(usize, usize)> {
+        let (tin, low, parent) = self.lowlink();
+        (0..self.n)
+            .filter(|&v| parent[v] != usize::MAX && low[v] > tin[parent[v]])
+            .map(|v| (parent[v], v))
+            .collect()
+    }
+    // vertices whose removal disconnects their component, increasing
+    fn articulation_points(&self) -> Vec<usize> {
+        let (tin, low, parent) = self.lowlink();
+        let mut cut = vec![false; self.n];
+        let mut root_children = vec![0; self.n];
+        for v in 0..self.n {
+            let p = parent[v];
+            if p == usize::MAX {
+                continue;
+            }
+            if parent[p] == usize::MAX {
+                root_children[p] += 1;
+            } else if low[v] >= tin[p] {
+                cut[p] = true;
+            }
+        }
+        (0..self.n)
+            .filter(|&v| cut[v] || root_children[v] >= 2)
+            .collect()
+    }
+}
+
+#[allow(dead_code)]
+impl Graph {
+    // sorts every adjacency list by (neighbor, weight). call it once after reading
+    // the edges, has_edge / edge_weight rely on it and neighbors come out sorted
+    fn finalize(&mut self) {
+        for a in &mut self.adj {
+            a.sort_unstable();
+        }
+    }
+    // O(log deg), needs finalize()
+    fn has_edge(&self, u: usize, v: usize) -> bool {
+        self.adj[u].binary_search_by_key(&v, |e| e.0).is_ok()
+    }
+    // lightest u -> v edge, needs finalize()
+    fn edge_weight(&self, u: usize, v: usize) -> Option<i64> {
+        let i = self.adj[u].partition_point(|e| e.0 < v);
+        self.adj[u].get(i).filter(|e| e.0 == v).map(|e| e.1)
+    }
+    fn neighbors(&self, u: usize) -> impl Iterator<Item = usize> + '_ {
+        self.adj[u].iter().map(|e| e.0)
+    }
+}
+
+// biconnected components (blocks) of an undirected graph and the block-cut tree:
+// nodes 0..n are the original vertices, n + i is blocks[i], and vertex v is joined to
+// every block containing it. cut vertices are the ones in 2+ blocks, an isolated
+// vertex is a block on its own
+#[allow(dead_code)]
+#[derive(Clone, Debug)]
+struct BlockCutTree {
+    blocks: Vec<Vec<usize>>,
+    is_cut: Vec<bool>,
+    tree: Graph,
+}
+#[allow(dead_code)]
+impl BlockCutTree {
+    fn new(g: &Graph) -> Self {
+        let n = g.n;
+        let mut tin = vec![usize::MAX; n];
+        let mut low = vec![0; n];
+        let mut blocks = Vec::new();
+        let mut timer = 0;
+        let mut vs = Vec::new();
+        for s in 0..n {
+            if tin[s] != usize::MAX {
+                continue;
+            }
+            tin[s] = timer;
+            low[s] = timer;
+            timer += 1;
+            if g.adj[s].iter().all(|e| e.0 == s) {
+                blocks.push(vec![s]);
+                continue;
+            }
+            vs.push(s);
+            let mut stack = vec![(s, 0)];
+            while let Some(&mut (u, ref mut i)) = stack.last_mut() {
+                if let Some(&(v, _)) = g.adj[u].get(*i) {
+                    *i += 1;
+                    if tin[v] == usize::MAX {
+                        tin[v] = timer;
+                        low[v] = timer;
+                        timer += 1;
+                        vs.push(v);
+                        stack.push((v, 0));
+                    } else {
+                        low[u] = low[u].min(tin[v]);
+                    }
+                    continue;
+                }
+                stack.pop();
+                let Some(&(p, _)) = stack.last() else {
+                    break;
+                };
+                low[p] = low[p].min(low[u]);
+                if low[u] >= tin[p] {
+                    // p separates u's subtree: pop it off as one block with p
+                    let mut b = vec![p];
+                    while let Some(x) = vs.pop() {
+                        b.push(x);
+                        if x == u {
+                            break;
+                        }
+                    }
+                    blocks.push(b);
+                }
+            }
+            vs.clear();
+        }
+        let mut tree = Graph::new(n + blocks.len());
+        let mut cnt = vec![0; n];
+        for (i, b) in blocks.iter().enumerate() {
+            for &v in b {
+                tree.add_undirected(v, n + i, 1);
+                cnt[v] += 1;
+            }
+        }
+        let is_cut = cnt.iter().map(|&c| c >= 2).collect();
+        Self {
+            blocks,
+            is_cut,
+            tree,
+        }
+    }
+    // tree node of block i
+    fn block_node(&self, i: usize) -> usize {
+        self.is_cut.len() + i
+    }
+    // blocks containing v (one unless v is a cut vertex)
+    fn blocks_of(&self, v: usize) -> impl Iterator<Item = usize> + '_ {
+        let n = self.is_cut.len();
+        self.tree.adj[v].iter().map(move |e| e.0 - n)
+    }
+}
+
+// t[v] = number of triangles through v in an undirected graph (self loops and
+// parallel edges ignored). edges are oriented from lower to higher (degree, id),
+// so every vertex has O(sqrt m) out-edges and the whole thing is O(m sqrt m).
+// local clustering of v is t[v] / (deg(v) * (deg(v) - 1) / 2)
+#[allow(dead_code)]
+fn triangles_per_vertex(g: &Graph) -> Vec<u64> {
+    let n = g.n;
+    let mut simple = g.clone();
+    simple.dedup_edges();
+    let key = |v: usize| (simple.adj[v].len(), v);
+    let out: Vec<Vec<usize>> = (0..n)
+        .map(|u| simple.neighbors(u).filter(|&v| key(u) < key(v)).collect())
+        .collect();
+    let mut t = vec![0; n];
+    let mut mark = vec![usize::MAX; n];
+    for u in 0..n {
+        for &v in &out[u] {
+            mark[v] = u;
+        }
+        for &v in &out[u] {
+            for &w in &out[v] {
+                if mark[w] == u {
+                    t[u] += 1;
+                    t[v] += 1;
+                    t[w] += 1;
+                }
+            }
+        }
+    }
+    t
+}
+#[allow(dead_code)]
+fn count_triangles(g: &Graph) -> u64 {
+    triangles_per_vertex(g).iter().sum::<u64>() / 3
+}
+
+// root distances on a tree whose edge weights change: the edge above v adds its
+// weight to the whole subtree of v, a contiguous range of the euler order, so a
+// range-add point-query fenwick does both in O(log n)
+#[allow(dead_code)]
+#[derive(Clone, Debug)]
+struct TreePathSum {
+    dfs: DfsResult,
+    w: Vec<i64>,
+    fw: Fenwick<i64>,
+}
+#[allow(dead_code)]
+impl TreePathSum {
+    fn new(g: &Graph, root: usize) -> Self {
+        let dfs = g.dfs_full(root);
+        let mut w = vec![0; g.n];
+        for u in 0..g.n {
+            for &(v, wt) in &g.adj[u] {
+                if dfs.parent[v] == u {
+                    w[v] = wt;
+                }
+            }
+        }
+        let mut t = Self {
+            dfs,
+            w: vec![0; g.n],
+            fw: Fenwick::new(g.n + 1),
+        };
+        // only root's component is in the euler order, the rest keeps tin = usize::MAX
+        for (v, &x) in w.iter().enumerate() {
+            if t.dfs.tin[v] != usize::MAX {
+                t.set_child_weight(v, x);
+            }
+        }
+        t
+    }
+    fn set_child_weight(&mut self, v: usize, x: i64) {
+        self.check_reached(v);
+        let d = x - self.w[v];
+        self.w[v] = x;
+        self.fw.add(self.dfs.tin[v], d);
+        self.fw.add(self.dfs.tout[v], -d);
+    }
+    // u and v must be adjacent in the tree
+    fn set_edge(&mut self, u: usize, v: usize, x: i64) {
+        let child = if self.dfs.parent[v] == u { v } else { u };
+        self.set_child_weight(child, x);
+    }
+    fn dist_root(&self, v: usize) -> i64 {
+        self.check_reached(v);
+        self.fw.sum(self.dfs.tin[v] + 1)
+    }
+    fn check_reached(&self, v: usize) {
+        assert!(
+            self.dfs.tin[v] != usize::MAX,
+            "vertex {} is not in the root's tree",
+            v
+        );
+    }
+    // the lca doesn't depend on weights, so a Lca built once stays valid
+    fn dist<T: Clone>(&self, u: usize, v: usize, lca: &Lca<T>) -> i64 {
+        self.dist_root(u) + self.dist_root(v) - 2 * self.dist_root(lca.lca(u, v))
+    }
+}
+
+thread_local! {
+    static EPS: std::cell::Cell<f64> = const { std::cell::Cell::new(1e-9) };
+}
+// some problems want 1e-6, set it once at the start of solve
+#[allow(dead_code)]
+fn set_eps(e: f64) {
+    EPS.with(|c| c.set(e));
+}
+fn eps() -> f64 {
+    EPS.with(|c| c.get())
+}
+#[allow(dead_code)]
+fn approx_cmp(a: f64, b: f64) -> std::cmp::Ordering {
+    if (a - b).abs() < eps() {
+        std::cmp::Ordering::Equal
+    } else if a < b {
+        std::cmp::Ordering::Less
+    } else {
+        std::cmp::Ordering::Greater
+    }
+}
+#[allow(dead_code)]
+fn approx_le(a: f64, b: f64) -> bool {
+    a < b + eps()
+}
+// snaps x to the closest integer if it is within eps of it
+#[allow(dead_code)]
+fn round_to_eps(x: f64) -> f64 {
+    if (x - x.round()).abs() < eps() {
+        x.round()
+    } else {
+        x
+    }
+}
+type Pt = (f64, f64);
+
+#[allow(dead_code)]
+fn cross(o: Pt, a: Pt, b: Pt) -> f64 {
+    (a.0 - o.0) * (b.1 - o.1) - (a.1 - o.1) * (b.0 - o.0)
+}
+
+#[allow(dead_code)]
+#[derive(Clone, Debug)]
+struct Polygon {
+    pts: Vec<Pt>,
+}
+#[allow(dead_code)]
+impl Polygon {
+    fn new(pts: Vec<Pt>) -> Self {
+        Self { pts }
+    }
+    // positive for counter-clockwise order
+    fn signed_area(&self) -> f64 {
+        let n = self.pts.len();
+        let mut s = 0.0;
+        for i in 0..n {
+            let (p, q) = (self.pts[i], self.pts[(i + 1) % n]);
+            s += p.0 * q.1 - p.1 * q.0;
+        }
+        s / 2.0
+    }
+    fn area(&self) -> f64 {
+        self.signed_area().abs()
+    }
+    fn is_clockwise(&self) -> bool {
+        self.signed_area() < -eps()
+    }
+    // reverses clockwise input so everything downstream can assume CCW
+    fn normalize_orientation(&mut self) {
+        if self.is_clockwise() {
+            self.pts.reverse();
+        }
+    }
+}
+
+// keeps the part of `poly` on the left of the directed line a -> b
+#[allow(dead_code)]
+fn polygon_cut(poly: &Polygon, a: Pt, b: Pt) -> Polygon {
+    let n = poly.pts.len();
+    let e = eps();
+    let mut res = Vec::new();
+    for i in 0..n {
+        let p = poly.pts[i];
+        let q = poly.pts[(i + 1) % n];
+        let cp = cross(a, b, p);
+        let cq = cross(a, b, q);
+        if cp >= -e {
+            res.push(p);
+        }
+        if (cp > e && cq < -e) || (cp < -e && cq > e) {
+            let t = cp / (cp - cq);
+            res.push((p.0 + (q.0 - p.0) * t, p.1 + (q.1 - p.1) * t));
+        }
+    }
+    Polygon::new(res)
+}
+
+// both polygons convex, either orientation. if either is degenerate (< 3 points,
+// or all collinear) the intersection is empty
+#[allow(dead_code)]
+fn convex_intersection(a: &Polygon, b: &Polygon) -> (Polygon, f64) {
+    let flat = |p: &Polygon| p.pts.len() < 3 || p.signed_area().abs() < eps();
+    if flat(a) || flat(b) {
+        return (Polygon::new(vec![]), 0.0);
+    }
+    let mut b = b.clone();
+    b.normalize_orientation();
+    let mut res = a.clone();
+    let m = b.pts.len();
+    for i in 0..m {
+        if res.pts.is_empty() {
+            break;
+        }
+        res = polygon_cut(&res, b.pts[i], b.pts[(i + 1) % m]);
+    }
+    if res.pts.len() < 3 {
+        return (Polygon::new(vec![]), 0.0);
+    }
+    let area = res.area();
+    (res, area)
+}
+
+#[allow(dead_code)]
+fn dot(o: Pt, a: Pt, b: Pt) -> f64 {
+    (a.0 - o.0) * (b.0 - o.0) + (a.1 - o.1) * (b.1 - o.1)
+}
+#[allow(dead_code)]
+fn dist(a: Pt, b: Pt) -> f64 {
+    (a.0 - b.0).hypot(a.1 - b.1)
+}
+
+// line through a and b, also used as the segment [a, b]
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug)]
+struct Line {
+    a: Pt,
+    b: Pt,
+}
+#[allow(dead_code)]
+impl Line {
+    fn new(a: Pt, b: Pt) -> Self {
+        Self { a, b }
+    }
+    fn project(&self, p: Pt) -> Pt {
+        // squared length, so compare against EPS squared
+        let len2 = dot(self.a, self.b, self.b);
+        if len2 < eps() * eps() {
+            return self.a;
+        }
+        let t = dot(self.a, self.b, p) / len2;
+        (
+            self.a.0 + (self.b.0 - self.a.0) * t,
+            self.a.1 + (self.b.1 - self.a.1) * t,
+        )
+    }
+    fn reflect(&self, p: Pt) -> Pt {
+        let h = self.project(p);
+        (2.0 * h.0 - p.0, 2.0 * h.1 - p.1)
+    }
+}
+
+#[allow(dead_code)]
+fn distance_to_point_segment(p: Pt, s: &Line) -> f64 {
+    if dot(s.a, s.b, p) < 0.0 {
+        return dist(p, s.a);
+    }
+    if dot(s.b, s.a, p) < 0.0 {
+        return dist(p, s.b);
+    }
+    dist(p, s.project(p))
+}
+
+#[allow(dead_code)]
+fn segments_intersect(s: &Line, t: &Line) -> bool {
+    let e = eps();
+    let on_seg = |p: Pt, q: &Line| cross(q.a, q.b, p).abs() < e && dot(p, q.a, q.b) < e;
+    let d1 = cross(s.a, s.b, t.a);
+    let d2 = cross(s.a, s.b, t.b);
+    let d3 = cross(t.a, t.b, s.a);
+    let d4 = cross(t.a, t.b, s.b);
+    if ((d1 > e && d2 < -e) || (d1 < -e && d2 > e)) && ((d3 > e && d4 < -e) || (d3 < -e && d4 > e))
+    {
+        return true;
+    }
+    on_seg(t.a, s) || on_seg(t.b, s) || on_seg(s.a, t) || on_seg(s.b, t)
+}
+
+#[allow(dead_code)]
+fn segment_segment_distance(s: &Line, t: &Line) -> f64 {
+    if segments_intersect(s, t) {
+        return 0.0;
+    }
+    distance_to_point_segment(s.a, t)
+        .min(distance_to_point_segment(s.b, t))
+        .min(distance_to_point_segment(t.a, s))
+        .min(distance_to_point_segment(t.b, s))
+}
+
+// point add, prefix sum. indices 0-based, sum(r) is over [0, r)
+#[allow(dead_code)]
+#[derive(Clone, Debug)]
+struct Fenwick<T = i64> {
+    t: Vec<T>,
+}
+#[allow(dead_code)]
+impl<T: Num> Fenwick<T> {
+    fn new(n: usize) -> Self {
+        Self {
+            t: vec![T::zero(); n + 1],
+        }
+    }
+    fn add(&mut self, i: usize, x: T) {
+        let mut i = i + 1;
+        while i < self.t.len() {
+            self.t[i] += x;
+            i += i & i.wrapping_neg();
+        }
+    }
+    fn sum(&self, r: usize) -> T {
+        let (mut i, mut s) = (r, T::zero());
+        while i > 0 {
+            s += self.t[i];
+            i -= i & i.wrapping_neg();
+        }
+        s
+    }
+    fn range_sum(&self, l: usize, r: usize) -> T {
+        self.sum(r) - self.sum(l)
+    }
+    // debug only: every node with the 0-indexed range [l, r) it sums, then the
+    // point values those imply, to spot off-by-one updates
+    fn dump(&self) {
+        if !debug::ON {
+            return;
+        }
+        for i in 1..self.t.len() {
+            let l = i - (i & i.wrapping_neg());
+            eprintln!("t[{}] = sum [{}, {}) = {:?}", i, l, i, self.t[i]);
+        }
+        let vals: Vec<T> = (1..self.t.len())
+            .map(|i| self.range_sum(i - 1, i))
+            .collect();
+        eprintln!("values = {:?}", vals);
+    }
+}
+
+// point set / add, range sum over [l, r). bottom-up, leaves at t[size..size + n]
+#[allow(dead_code)]
+#[derive(Clone, Debug)]
+struct SumSegmentTree {
+    size: usize,
+    t: Vec<i64>,
+}
+#[allow(dead_code)]
+impl SumSegmentTree {
+    fn new(a: &[i64]) -> Self {
+        let size = a.len().next_power_of_two();
+        let mut t = vec![0; 2 * size];
+        t[size..size + a.len()].copy_from_slice(a);
+        for i in (1..size).rev() {
+            t[i] = t[2 * i] + t[2 * i + 1];
+        }
+        Self { size, t }
+    }
+    fn set(&mut self, i: usize, x: i64) {
+        let mut i = i + self.size;
+        self.t[i] = x;
+        while i > 1 {
+            i /= 2;
+            self.t[i] = self.t[2 * i] + self.t[2 * i + 1];
+        }
+    }
+    fn add(&mut self, i: usize, x: i64) {
+        let cur = self.t[i + self.size];
+        self.set(i, cur + x);
+    }
+    fn sum(&self, l: usize, r: usize) -> i64 {
+        let (mut l, mut r) = (l + self.size, r + self.size);
+        let mut s = 0;
+        while l < r {
+            if l & 1 == 1 {
+                s += self.t[l];
+                l += 1;
+            }
+            if r & 1 == 1 {
+                r -= 1;
+                s += self.t[r];
+            }
+            l /= 2;
+            r /= 2;
+        }
+        s
+    }
+    // debug only: one line per level, root first, every node as [l, r)=sum
+    fn dump(&self) {
+        if !debug::ON {
+            return;
+        }
+        let (mut first, mut len) = (1, self.size);
+        while first <= self.size {
+            let line: Vec<String> = (first..2 * first)
+                .map(|i| {
+                    let l = (i - first) * len;
+                    format!("[{}, {})={}", l, l + len, self.t[i])
+                })
+                .collect();
+            eprintln!("{}", line.join("  "));
+            first *= 2;
+            len /= 2;
+        }
+    }
+}
+
+// lazy range add, range min over [l, r). mn[v] is the min of v's range counting
+// every add applied at v or below, lz[v] is what v still owes its children
+#[allow(dead_code)]
+#[derive(Clone, Debug)]
+struct SegmentTree {
+    n: usize,
+    mn: Vec<i64>,
+    lz: Vec<i64>,
+}
+#[allow(dead_code)]
+impl SegmentTree {
+    fn new(a: &[i64]) -> Self {
+        let n = a.len();
+        let mut st = Self {
+            n,
+            mn: vec![0; 4 * n.max(1)],
+            lz: vec![0; 4 * n.max(1)],
+        };
+        if n > 0 {
+            st.build(1, 0, n, a);
+        }
+        st
+    }
+    fn build(&mut self, v: usize, l: usize, r: usize, a: &[i64]) {
+        if r - l == 1 {
+            self.mn[v] = a[l];
+            return;
+        }
+        let m = (l + r) / 2;
+        self.build(2 * v, l, m, a);
+        self.build(2 * v + 1, m, r, a);
+        self.mn[v] = self.mn[2 * v].min(self.mn[2 * v + 1]);
+    }
+    fn push(&mut self, v: usize) {
+        let z = self.lz[v];
+        if z != 0 {
+            for c in [2 * v, 2 * v + 1] {
+                self.mn[c] += z;
+                self.lz[c] += z;
+            }
+            self.lz[v] = 0;
+        }
+    }
+    // a[i] += x for every i in [l, r)
+    fn add(&mut self, l: usize, r: usize, x: i64) {
+        if l < r {
+            self.add_rec(1, 0, self.n, l, r, x);
+        }
+    }
+    fn add_rec(&mut self, v: usize, nl: usize, nr: usize, l: usize, r: usize, x: i64) {
+        if r <= nl || nr <= l {
+            return;
+        }
+        if l <= nl && nr <= r {
+            self.mn[v] += x;
+            self.lz[v] += x;
+            return;
+        }
+        self.push(v);
+        let m = (nl + nr) / 2;
+        self.add_rec(2 * v, nl, m, l, r, x);
+        self.add_rec(2 * v + 1, m, nr, l, r, x);
+        self.mn[v] = self.mn[2 * v].min(self.mn[2 * v + 1]);
+    }
+    // min over [l, r), i64::MAX if empty. pending lazies are added on the way
+    // back up instead of pushed, so this only needs &self
+    fn min(&self, l: usize, r: usize) -> i64 {
+        if l < r {
+            self.min_rec(1, 0, self.n, l, r)
+        } else {
+            i64::MAX
+        }
+    }
+    fn min_rec(&self, v: usize, nl: usize, nr: usize, l: usize, r: usize) -> i64 {
+        if r <= nl || nr <= l {
+            return i64::MAX;
+        }
+        if l <= nl && nr <= r {
+            return self.mn[v];
+        }
+        let m = (nl + nr) / 2;
+        let best = self
+            .min_rec(2 * v, nl, m, l, r)
+            .min(self.min_rec(2 * v + 1, m, nr, l, r));
+        best + self.lz[v]
+    }
+    // debug only: one line per level as [l, r)=min, with +z when the node still owes
+    // z to its children (a node's min doesn't include what its ancestors owe), then
+    // the actual values
+    fn dump(&self) {
+        if !debug::ON {
+            return;
+        }
+        let mut level = vec![(1, 0, self.n)];
+        while !level.is_empty() {
+            let line: Vec<String> = level
+                .iter()
+                .map(|&(v, l, r)| {
+                    if r - l > 1 && self.lz[v] != 0 {
+                        format!("[{}, {})={} +{}", l, r, self.mn[v], self.lz[v])
+                    } else {
+                        format!("[{}, {})={}", l, r, self.mn[v])
+                    }
+                })
+                .collect();
+            eprintln!("{}", line.join("  "));
+            level = level
+                .iter()
+                .filter(|&&(_, l, r)| r - l > 1)
+                .flat_map(|&(v, l, r)| {
+                    let m = (l + r) / 2;
+                    [(2 * v, l, m), (2 * v + 1, m, r)]
+                })
+                .collect();
+        }
+        let vals: Vec<i64> = (0..self.n).map(|i| self.min(i, i + 1)).collect();
+        eprintln!("values = {:?}", vals);
+    }
+}
+
+fn solve(scan: &mut Scanner, out: &mut OutWriter<impl Write>) {
+    let mut n: usize = scan.next();
+    let a: usize = scan.next();
+    let b: usize = scan.next();
+    let n1: usize = scan.next();
+    let r: usize = scan.next();
+    let a1 = factors(n as i128);
+    writeln!(out, "{:?}", a1).ok();
+    let gc = gcd(a, b);
+    writeln!(out, "{} {}", gc, lcm(a, b)).ok();
+    let val = (5 * fltinv(3, MOD)) % MOD;
+    writeln!(out, "{}", val).ok();
+    writeln!(out, "{}", modexp(2, 100000, 1000000007)).ok();
+    writeln!(out, "{:?}", pcfact(n)).ok();
+    writeln!(out, "{}", intsqrt(n)).ok();
+    let (fact, inv_fact) = pcfact(n);
+    let combinations = ncr(n1, r, &fact, &inv_fact);
+    let permutations = npr(n1, r, &fact, &inv_fact);
+    writeln!(out, "15C3 = {}", combinations).ok();
+    writeln!(out, "15P3 = {}", permutations).ok();
+    writeln!(out, "{:?}", sieve(n)).ok();
+    writeln!(out, "{:?}", pfactors(n)).ok();
+}
+
+// local debugging helpers, prints go to stderr, only in dev builds or with the
+// `local` feature (see dbg_print! above)
+#[allow(dead_code)]
+mod debug {
+    use std::fmt::Display;
+    use std::time::{Duration, Instant};
+
+    pub const ON: bool = cfg!(any(feature = "local", debug_assertions));
+
+    pub fn print_grid<T: Display + PartialEq>(g: &[Vec<T>]) {
+        print_grid_with(g, false, None);
+    }
+
+    // aligned dump of a dp table, cells equal to `sentinel` (INF, -1, ..) show as '.'
+    pub fn print_grid_with<T: Display + PartialEq>(
+        g: &[Vec<T>],
+        headers: bool,
+        sentinel: Option<&T>,
+    ) {
+        if !ON {
+            return;
+        }
+        let cells: Vec<Vec<String>> = g
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|x| match sentinel {
+                        Some(s) if s == x => ".".to_string(),
+                        _ => x.to_string(),
+                    })
+                    .collect()
+            })
+            .collect();
+        let cols = cells.iter().map(|r| r.len()).max().unwrap_or(0);
+        let mut w = vec![0; cols];
+        for row in &cells {
+            for (j, c) in row.iter().enumerate() {
+                w[j] = w[j].max(c.len());
+            }
+        }
+        let rw = g.len().saturating_sub(1).to_string().len();
+        if headers {
+            for (j, wj) in w.iter_mut().enumerate() {
+                *wj = (*wj).max(j.to_string().len());
+            }
+            let head: Vec<String> = (0..cols).map(|j| format!("{:>1$}", j, w[j])).collect();
+            eprintln!("{:>rw$} | {}", "", head.join(" "), rw = rw);
+        }
+        for (i, row) in cells.iter().enumerate() {
+            let line: Vec<String> = row
+                .iter()
+                .enumerate()
+                .map(|(j, c)| format!("{:>1$}", c, w[j]))
+                .collect();
+            if headers {
+                eprintln!("{:>rw$} | {}", i, line.join(" "), rw = rw);
+            } else {
+                eprintln!("{}", line.join(" "));
+            }
+        }
+    }
+
+    // rough bytes used, to check a big table fits in 256MB. impls only report what
+    // they own on the heap, the inline part is size_of_val, so nothing is ever
+    // subtracted (padding makes a tuple bigger than the sum of its fields)
+    pub trait MemSize {
+        fn heap_size(&self) -> usize;
+        fn mem_size(&self) -> usize {
+            std::mem::size_of_val(self) + self.heap_size()
+        }
+    }
+
+    macro_rules! flat_mem_size {
+        ($($t:ty),*) => {
+            $(impl MemSize for $t {
+                fn heap_size(&self) -> usize {
+                    0
+                }
+            })*
+        };
+    }
+    flat_mem_size!(
+        u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64, bool, char
+    );
+
+    impl<T: MemSize> MemSize for Vec<T> {
+        fn heap_size(&self) -> usize {
+            let inner: usize = self.iter().map(|x| x.heap_size()).sum();
+            self.capacity() * std::mem::size_of::<T>() + inner
+        }
+    }
+
+    impl MemSize for String {
+        fn heap_size(&self) -> usize {
+            self.capacity()
+        }
+    }
+
+    impl<A: MemSize, B: MemSize> MemSize for (A, B) {
+        fn heap_size(&self) -> usize {
+            self.0.heap_size() + self.1.heap_size()
+        }
+    }
+
+    // tree nodes hold up to 11 entries, count them as about 2/3 full
+    impl<K: MemSize, V: MemSize> MemSize for std::collections::BTreeMap<K, V> {
+        fn heap_size(&self) -> usize {
+            let entry = std::mem::size_of::<K>() + std::mem::size_of::<V>();
+            let inner: usize = self
+                .iter()
+                .map(|(k, v)| k.heap_size() + v.heap_size())
+                .sum();
+            self.len() * entry * 3 / 2 + inner
+        }
+    }
+
+    // hashbrown keeps 1 control byte per bucket and a load factor of 7/8
+    impl<K: MemSize, V: MemSize> MemSize for std::collections::HashMap<K, V> {
+        fn heap_size(&self) -> usize {
+            let slot = std::mem::size_of::<(K, V)>() + 1;
+            let inner: usize = self
+                .iter()
+                .map(|(k, v)| k.heap_size() + v.heap_size())
+                .sum();
+            self.capacity() * 8 / 7 * slot + inner
+        }
+    }
+
+    impl MemSize for super::Polygon {
+        fn heap_size(&self) -> usize {
+            self.pts.heap_size()
+        }
+    }
+
+    // library structs: the sum of what their fields own
+    macro_rules! fields_mem_size {
+        ($($t:ident { $($f:ident),* })*) => {
+            $(impl MemSize for super::$t {
+                fn heap_size(&self) -> usize {
+                    0 $(+ self.$f.heap_size())*
+                }
+            })*
+        };
+    }
+    fields_mem_size!(
+        Graph { adj }
+        DfsResult { parent, order, tin, tout }
+        Bitset { w }
+        IntervalDsu { nxt }
+        TimeDsu { par, size, at }
+        EulerLca { first, depth, sp }
+        TreePathSum { dfs, w, fw }
+        SumSegmentTree { t }
+        SegmentTree { mn, lz }
+    );
+
+    impl<T: MemSize> MemSize for super::Fenwick<T> {
+        fn heap_size(&self) -> usize {
+            self.t.heap_size()
+        }
+    }
+
+    impl<T: MemSize> MemSize for super::PrefixSum<T> {
+        fn heap_size(&self) -> usize {
+            self.pre.heap_size()
+        }
+    }
+
+    impl<T: MemSize> MemSize for super::PrefixSum2D<T> {
+        fn heap_size(&self) -> usize {
+            self.pre.heap_size()
+        }
+    }
+
+    impl<T: MemSize> MemSize for super::BinaryLifting<T> {
+        fn heap_size(&self) -> usize {
+            self.up.heap_size() + self.val.heap_size()
+        }
+    }
+
+    impl<T: MemSize> MemSize for super::Lca<T> {
+        fn heap_size(&self) -> usize {
+            let agg = self.agg_up.heap_size() + self.agg_down.heap_size();
+            self.up.heap_size() + self.depth.heap_size() + self.dist.heap_size() + agg
+        }
+    }
+
+    pub fn print_mem<T: MemSize>(label: &str, x: &T) {
+        if ON {
+            eprintln!(
+                "[{}] ~{:.2} MB",
+                label,
+                x.mem_size() as f64 / (1 << 20) as f64
+            );
+        }
+    }
+
+    thread_local! {
+        static DEPTH: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+    }
+
+    pub struct TraceGuard {
+        label: Option<String>,
+    }
+
+    impl TraceGuard {
+        pub fn enter(enabled: bool, label: impl FnOnce() -> String) -> Self {
+            if !(enabled && ON) {
+                return Self { label: None };
+            }
+            let label = label();
+            let d = DEPTH.with(|c| c.replace(c.get() + 1));
+            eprintln!("{}-> {}", "  ".repeat(d), label);
+            Self { label: Some(label) }
+        }
+    }
+
+    impl Drop for TraceGuard {
+        fn drop(&mut self) {
+            if let Some(label) = &self.label {
+                let d = DEPTH.with(|c| {
+                    c.set(c.get() - 1);
+                    c.get()
+                });
+                eprintln!("{}<- {}", "  ".repeat(d), label);
+            }
+        }
+    }
+
+    pub struct Stopwatch {
+        start: Instant,
+    }
+
+    impl Stopwatch {
+        pub fn new() -> Self {
+            Self {
+                start: Instant::now(),
+            }
+        }
+        pub fn elapsed_ms(&self) -> u128 {
+            self.start.elapsed().as_millis()
+        }
+        pub fn print(&self, label: &str) {
+            if ON {
+                eprintln!("[{}] {} ms", label, self.elapsed_ms());
+            }
+        }
+    }
+
+    // prints how long the enclosing scope took: `let _t = ScopedTimer::new("dp");`
+    pub struct ScopedTimer {
+        label: &'static str,
+        watch: Stopwatch,
+    }
+
+    impl ScopedTimer {
+        pub fn new(label: &'static str) -> Self {
+            Self {
+                label,
+                watch: Stopwatch::new(),
+            }
+        }
+    }
+
+    impl Drop for ScopedTimer {
+        fn drop(&mut self) {
+            self.watch.print(self.label);
+        }
+    }
+
+    // for randomized / iterative deepening solutions: stop while check() is false
+    pub struct TimeLimit {
+        start: Instant,
+        limit: Duration,
+    }
+
+    impl TimeLimit {
+        pub fn new(ms: u64) -> Self {
+            Self {
+                start: Instant::now(),
+                limit: Duration::from_millis(ms),
+            }
+        }
+        // true while there is still time left
+        pub fn check(&self) -> bool {
+            self.start.elapsed() < self.limit
+        }
+        // 0.0 at the start, 1.0 at the limit (simulated annealing temperature etc.)
+        pub fn elapsed_frac(&self) -> f64 {
+            self.start.elapsed().as_secs_f64() / self.limit.as_secs_f64()
+        }
+    }
+}
+
+// `./sol --samples` runs solve on every tests/samples/NAME.in and diffs against NAME.out
+mod testing {
+    use std::fs;
+
+    #[allow(dead_code)]
+    pub enum Compare {
+        Exact,
+        // tokens that parse as floats only need abs or rel error <= eps
+        Float(f64),
+        // any order of output lines is accepted
+        Unordered,
+    }
+
+    fn lines(s: &str) -> Vec<String> {
+        s.lines()
+            .map(|l| l.split_whitespace().collect::<Vec<_>>().join(" "))
+            .filter(|l| !l.is_empty())
+            .collect()
+    }
+
+    // a is the expected token: like judges, the relative error is measured against it
+    fn token_eq(a: &str, b: &str, eps: f64) -> bool {
+        if a == b {
+            return true;
+        }
+        match (a.parse::<f64>(), b.parse::<f64>()) {
+            (Ok(x), Ok(y)) => (x - y).abs() <= eps * x.abs().max(1.0),
+            _ => false,
+        }
+    }
+
+    pub fn check(expected: &str, got: &str, cmp: &Compare) -> bool {
+        let (mut e, mut g) = (lines(expected), lines(got));
+        match cmp {
+            Compare::Exact => e == g,
+            Compare::Unordered => {
+                e.sort();
+                g.sort();
+                e == g
+            }
+            Compare::Float(eps) => {
+                let et: Vec<&str> = e.iter().flat_map(|l| l.split(' ')).collect();
+                let gt: Vec<&str> = g.iter().flat_map(|l| l.split(' ')).collect();
+                et.len() == gt.len() && et.iter().zip(&gt).all(|(a, b)| token_eq(a, b, *eps))
+            }
+        }
+    }
+
+    pub fn run_samples(dir: &str, run: fn(&str) -> String, cmp: Compare) -> bool {
+        let mut ins: Vec<_> = fs::read_dir(dir)
+            .unwrap_or_else(|_| panic!("no sample dir '{}'", dir))
+            .map(|e| e.expect("read_dir failed").path())
+            .filter(|p| p.extension().is_some_and(|x| x == "in"))
+            .collect();
+        ins.sort();
+        let mut passed = 0;
+        for input in &ins {
+            let name = input.file_stem().unwrap().to_string_lossy();
+            let expected = match fs::read_to_string(input.with_extension("out")) {
+                Ok(s) => s,
+                Err(_) => {
+                    eprintln!("{}: no .out file, skipped", name);
+                    continue;
+                }
+            };
+            let got = run(&fs::read_to_string(input).expect("failed to read sample"));
+            if check(&expected, &got, &cmp) {
+                passed += 1;
+                eprintln!("{}: OK", name);
+            } else {
+                eprintln!("{}: WA\n--- expected\n{}--- got\n{}", name, expected, got);
+            }
+        }
+        eprintln!("{}/{} passed", passed, ins.len());
+        passed == ins.len()
+    }
+}
+
+// xorshift64, seeded from the clock unless a seed is given (to replay a failure)
+#[allow(dead_code)]
+struct Rng(u64);
+#[allow(dead_code)]
+impl Rng {
+    fn new() -> Self {
+        let t = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos() as u64;
+        Self::with_seed(t)
+    }
+    fn with_seed(seed: u64) -> Self {
+        Self((seed ^ 0x9e37_79b9_7f4a_7c15) | 1)
+    }
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+    // inclusive on both ends, any lo <= hi (the span is taken in i128)
+    fn range(&mut self, lo: i64, hi: i64) -> i64 {
+        assert!(lo <= hi, "Rng::range: empty range [{}, {}]", lo, hi);
+        let span = hi as i128 - lo as i128 + 1;
+        if span > u64::MAX as i128 {
+            return self.next_u64() as i64;
+        }
+        lo.wrapping_add((self.next_u64() % span as u64) as i64)
+    }
+}
+
+// random inputs for stress tests, all vertices 0-indexed
+#[allow(dead_code)]
+mod gen {
+    use super::Rng;
+    use std::collections::{BinaryHeap, HashSet};
+
+    pub fn array(rng: &mut Rng, n: usize, lo: i64, hi: i64) -> Vec<i64> {
+        (0..n).map(|_| rng.range(lo, hi)).collect()
+    }
+
+    // needs hi - lo + 1 >= n
+    pub fn distinct_array(rng: &mut Rng, n: usize, lo: i64, hi: i64) -> Vec<i64> {
+        let mut seen = HashSet::new();
+        let mut res = Vec::with_capacity(n);
+        while res.len() < n {
+            let x = rng.range(lo, hi);
+            if seen.insert(x) {
+                res.push(x);
+            }
+        }
+        res
+    }
+
+    pub fn shuffle<T>(rng: &mut Rng, v: &mut [T]) {
+        for i in (1..v.len()).rev() {
+            let j = rng.range(0, i as i64) as usize;
+            v.swap(i, j);
+        }
+    }
+
+    pub fn permutation(rng: &mut Rng, n: usize) -> Vec<usize> {
+        let mut p: Vec<usize> = (0..n).collect();
+        shuffle(rng, &mut p);
+        p
+    }
+
+    pub fn string(rng: &mut Rng, n: usize, alphabet: &[u8]) -> String {
+        assert!(!alphabet.is_empty(), "gen::string: empty alphabet");
+        (0..n)
+            .map(|_| alphabet[rng.range(0, alphabet.len() as i64 - 1) as usize] as char)
+            .collect()
+    }
+
+    #[derive(Clone, Copy)]
+    pub enum TreeShape {
+        Path,
+        Star,
+        // a path with leaves hanging off it
+        Caterpillar,
+        // every labeled tree equally likely (random Prufer sequence)
+        Uniform,
+    }
+
+    pub fn tree(rng: &mut Rng, n: usize, shape: TreeShape) -> Vec<(usize, usize)> {
+        if n <= 1 {
+            return vec![];
+        }
+        let mut edges = match shape {
+            TreeShape::Path => (1..n).map(|i| (i - 1, i)).collect(),
+            TreeShape::Star => (1..n).map(|i| (0, i)).collect(),
+            TreeShape::Caterpillar => {
+                let spine = rng.range(1, n as i64) as usize;
+                let mut e: Vec<_> = (1..spine).map(|i| (i - 1, i)).collect();
+                for v in spine..n {
+                    e.push((rng.range(0, spine as i64 - 1) as usize, v));
+                }
+                e
+            }
+            TreeShape::Uniform => prufer_tree(rng, n),
+        };
+        let label = permutation(rng, n);
+        for e in edges.iter_mut() {
+            *e = (label[e.0], label[e.1]);
+        }
+        shuffle(rng, &mut edges);
+        edges
+    }
+
+    fn prufer_tree(rng: &mut Rng, n: usize) -> Vec<(usize, usize)> {
+        let seq: Vec<usize> = (0..n - 2)
+            .map(|_| rng.range(0, n as i64 - 1) as usize)
+            .collect();
+        let mut deg = vec![1; n];
+        for &x in &seq {
+            deg[x] += 1;
+        }
+        let mut leaves: BinaryHeap<std::cmp::Reverse<usize>> = (0..n)
+            .filter(|&v| deg[v] == 1)
+            .map(std::cmp::Reverse)
+            .collect();
+        let mut edges = Vec::with_capacity(n - 1);
+        for &x in &seq {
+            let std::cmp::Reverse(leaf) = leaves.pop().unwrap();
+            edges.push((leaf, x));
+            deg[x] -= 1;
+            if deg[x] == 1 {
+                leaves.push(std::cmp::Reverse(x));
+            }
+        }
+        let std::cmp::Reverse(a) = leaves.pop().unwrap();
+        let std::cmp::Reverse(b) = leaves.pop().unwrap();
+        edges.push((a, b));
+        edges
+    }
+
+    // simple connected graph (no self loops / multi-edges), n - 1 <= m <= n(n-1)/2
+    pub fn connected_graph(rng: &mut Rng, n: usize, m: usize) -> Vec<(usize, usize)> {
+        assert!(n > 0, "gen::connected_graph: n must be at least 1");
+        assert!(m + 1 >= n && m <= n * (n - 1) / 2, "bad edge count");
+        let mut edges = tree(rng, n, TreeShape::Uniform);
+        let mut seen: HashSet<(usize, usize)> =
+            edges.iter().map(|&(u, v)| (u.min(v), u.max(v))).collect();
+        while edges.len() < m {
+            let u = rng.range(0, n as i64 - 1) as usize;
+            let v = rng.range(0, n as i64 - 1) as usize;
+            if u != v && seen.insert((u.min(v), u.max(v))) {
+                edges.push((u, v));
+            }
+        }
+        edges
+    }
+}
+
+// brute vs fast on random inputs. gen gets the rng and a size that grows with
+// the iteration, so the first mismatch found is already a small one
+mod stress {
+    use super::{testing, Rng};
+
+    // seeded from the clock, or from RCP_SEED=s to replay a run; the seed is printed
+    // with every mismatch
+    #[allow(dead_code)]
+    pub fn run(
+        gen: impl FnMut(&mut Rng, usize) -> String,
+        brute: fn(&str) -> String,
+        fast: fn(&str) -> String,
+        iterations: usize,
+    ) -> Option<String> {
+        let seed = match std::env::var("RCP_SEED") {
+            Ok(s) => s.trim().parse().expect("RCP_SEED is not a u64"),
+            Err(_) => Rng::new().next_u64(),
+        };
+        run_with_seed(seed, gen, brute, fast, iterations)
+    }
+
+    #[allow(dead_code)]
+    pub fn run_with_seed(
+        seed: u64,
+        mut gen: impl FnMut(&mut Rng, usize) -> String,
+        brute: fn(&str) -> String,
+        fast: fn(&str) -> String,
+        iterations: usize,
+    ) -> Option<String> {
+        let mut rng = Rng::with_seed(seed);
+        for it in 0..iterations {
+            let size = 1 + it * 20 / iterations.max(1);
+            let input = gen(&mut rng, size);
+            let (want, got) = (brute(&input), fast(&input));
+            if !testing::check(&want, &got, &testing::Compare::Exact) {
+                let input = shrink(&mut gen, &mut rng, brute, fast, size).unwrap_or(input);
+                eprintln!(
+                    "mismatch (seed {}, RCP_SEED={} replays it) on:\n{}--- brute\n{}--- fast\n{}",
+                    seed,
+                    seed,
+                    input,
+                    brute(&input),
+                    fast(&input)
+                );
+                return Some(input);
+            }
+        }
+        eprintln!("{} tests passed (seed {})", iterations, seed);
+        None
+    }
+
+    // retries smaller sizes to find a shorter failing input
+    fn shrink(
+        gen: &mut impl FnMut(&mut Rng, usize) -> String,
+        rng: &mut Rng,
+        brute: fn(&str) -> String,
+        fast: fn(&str) -> String,
+        size: usize,
+    ) -> Option<String> {
+        for s in 1..size {
+            for _ in 0..200 {
+                let input = gen(rng, s);
+                if !testing::check(&brute(&input), &fast(&input), &testing::Compare::Exact) {
+                    return Some(input);
+                }
+            }
+        }
+        None
+    }
+}
+
+// feeds `input` to solve and hands back everything it printed, for asserts on samples
+#[allow(dead_code)]
+fn run_solve(input: &str) -> String {
+    let mut scan = Scanner::from_input(input);
+    let mut out = OutWriter::new(Vec::new());
+    solve(&mut scan, &mut out);
+    String::from_utf8(out.into_inner()).expect("output is not utf8")
+}
+
+fn main() {
+    if std::env::args().any(|a| a == "--samples") {
+        // non-zero exit on any WA so scripts / CI can tell
+        if !testing::run_samples("tests/samples", run_solve, testing::Compare::Exact) {
+            std::process::exit(1);
+        }
+        return;
+    }
     let mut scan = Scanner::new();
-    let mut out = io::BufWriter::new(io::stdout());
+    let mut out = init_writer();
     solve(&mut scan, &mut out);
 }